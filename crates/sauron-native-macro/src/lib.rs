@@ -0,0 +1,309 @@
+//! The `view!` macro writes a widget tree in a syntax like html,
+//! and expands to the builders in `sauron_native::widget`
+//! and the attribute functions in `sauron_native::widget::attribute`.
+//!
+//! ```ignore
+//! view! {
+//!     <column spacing=8.0>
+//!         <text>"What needs to be done?"</text>
+//!         <text_input value={self.value.clone()} on_input={|event| Msg::Update(event)} />
+//!         <button label="Add" on_click={|_| Msg::Add} disabled={self.value.is_empty()} />
+//!         { self.entries.iter().map(view_entry).collect::<Vec<_>>() }
+//!     </column>
+//! }
+//! ```
+//!
+//! An attribute without a value is set to true, such as `<checkbox disabled />`,
+//! and the icon of a button is `<button label="Save" icon={Icon::Save} />`.
+//! A `{...}` block in the children is a node, an optional node or a list of nodes.
+//! The widgets which hold data, such as the bytes of an image, take it as an attribute:
+//! `<image src={bytes} />`, `<separator orientation={Orientation::Horizontal} />`,
+//! `<icon icon={Icon::Save} size={IconSize::Small} />`, `<line_chart chart={chart} />`,
+//! `<bar_chart chart={chart} />` and `<sparkline series={series} />`.
+extern crate proc_macro;
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input, token, Block, Expr, ExprBlock, ExprLit, Ident, Lit, LitBool, LitStr, Result,
+    Stmt, Token,
+};
+
+#[proc_macro]
+pub fn view(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let element = parse_macro_input!(input as Element);
+    match element.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// the widgets known to the macro, listed in the error of an unknown widget
+const WIDGETS: &str = "column, row, frame, expander, button, text_input, checkbox, radio, \
+                       text, image, separator, icon, line_chart, bar_chart, sparkline";
+
+/// how the builder of a widget is called
+enum Kind {
+    /// with the attributes and the children
+    Container,
+    /// with the attributes only
+    Leaf,
+    /// with the attributes and the text in its children
+    Text,
+    /// a variant of `Widget` holding data, which is taken from these attributes
+    Data(&'static str, &'static [&'static str]),
+}
+
+impl Kind {
+    fn of(name: &str) -> Option<Self> {
+        let kind = match name {
+            "column" | "row" | "frame" | "expander" => Kind::Container,
+            "button" | "text_input" | "checkbox" | "radio" => Kind::Leaf,
+            "text" => Kind::Text,
+            "image" => Kind::Data("Image", &["src"]),
+            "separator" => Kind::Data("Separator", &["orientation"]),
+            "icon" => Kind::Data("Icon", &["icon", "size"]),
+            "line_chart" => Kind::Data("LineChart", &["chart"]),
+            "bar_chart" => Kind::Data("BarChart", &["chart"]),
+            "sparkline" => Kind::Data("Sparkline", &["series"]),
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+struct Element {
+    name: Ident,
+    attrs: Vec<Attr>,
+    children: Vec<Child>,
+}
+
+struct Attr {
+    name: Ident,
+    value: Expr,
+}
+
+enum Child {
+    Element(Element),
+    Text(LitStr),
+    Block(Expr),
+}
+
+/// the content of a `{...}` block as an expression,
+/// a single expression is taken out of the braces
+fn parse_block(input: ParseStream) -> Result<Expr> {
+    let content;
+    let brace_token = braced!(content in input);
+    let mut stmts = content.call(Block::parse_within)?;
+    if let [Stmt::Expr(_)] = stmts.as_slice() {
+        if let Some(Stmt::Expr(expr)) = stmts.pop() {
+            return Ok(expr);
+        }
+    }
+    Ok(Expr::Block(ExprBlock {
+        attrs: vec![],
+        label: None,
+        block: Block { brace_token, stmts },
+    }))
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![<]>()?;
+        let name: Ident = input.parse()?;
+        let mut attrs = vec![];
+        while !(input.peek(Token![>]) || input.peek(Token![/])) {
+            attrs.push(input.parse()?);
+        }
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(Element {
+                name,
+                attrs,
+                children: vec![],
+            });
+        }
+        input.parse::<Token![>]>()?;
+        let mut children = vec![];
+        while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+            if input.is_empty() {
+                let message = format!("`<{}>` is not closed", name);
+                return Err(syn::Error::new(name.span(), message));
+            }
+            children.push(input.parse()?);
+        }
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let closing: Ident = input.parse()?;
+        if closing != name {
+            let message = format!("expecting `</{}>`", name);
+            return Err(syn::Error::new(closing.span(), message));
+        }
+        input.parse::<Token![>]>()?;
+        Ok(Element {
+            name,
+            attrs,
+            children,
+        })
+    }
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        if !input.peek(Token![=]) {
+            let value = Expr::Lit(ExprLit {
+                attrs: vec![],
+                lit: Lit::Bool(LitBool {
+                    value: true,
+                    span: name.span(),
+                }),
+            });
+            return Ok(Attr { name, value });
+        }
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(token::Brace) {
+            parse_block(input)?
+        } else {
+            Expr::Lit(ExprLit {
+                attrs: vec![],
+                lit: input.parse()?,
+            })
+        };
+        Ok(Attr { name, value })
+    }
+}
+
+impl Parse for Child {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![<]) {
+            Ok(Child::Element(input.parse()?))
+        } else if input.peek(LitStr) {
+            Ok(Child::Text(input.parse()?))
+        } else if input.peek(token::Brace) {
+            Ok(Child::Block(parse_block(input)?))
+        } else {
+            Err(input.error("expecting a widget, a text or a `{...}` block"))
+        }
+    }
+}
+
+impl Element {
+    fn expand(self) -> Result<TokenStream> {
+        let Element {
+            name,
+            mut attrs,
+            children,
+        } = self;
+        let kind = Kind::of(&name.to_string()).ok_or_else(|| {
+            let message = format!("unknown widget `{}`, expecting one of: {}", name, WIDGETS);
+            syn::Error::new(name.span(), message)
+        })?;
+        match kind {
+            Kind::Container => {
+                let attrs = expand_attrs(attrs);
+                let children = expand_children(children)?;
+                Ok(quote! { ::sauron_native::widget::#name(#attrs, #children) })
+            }
+            Kind::Leaf => {
+                no_children(&name, &children)?;
+                let attrs = expand_attrs(attrs);
+                Ok(quote! { ::sauron_native::widget::#name(#attrs) })
+            }
+            Kind::Text => {
+                let content = match children.as_slice() {
+                    [Child::Text(txt)] => quote! { #txt },
+                    [Child::Block(expr)] => quote! { #expr },
+                    _ => {
+                        let message = "`<text>` takes a single text or `{...}` block";
+                        return Err(syn::Error::new(name.span(), message));
+                    }
+                };
+                let attrs = expand_attrs(attrs);
+                Ok(quote! {
+                    {
+                        let __text = #content;
+                        ::sauron_native::widget::text_with(#attrs, &__text)
+                    }
+                })
+            }
+            Kind::Data(variant, fields) => {
+                no_children(&name, &children)?;
+                let mut data = vec![];
+                for field in fields {
+                    let position = attrs.iter().position(|att| att.name == field);
+                    match position {
+                        Some(position) => data.push(attrs.remove(position).value),
+                        None => {
+                            let message = format!("`<{}>` needs the `{}` attribute", name, field);
+                            return Err(syn::Error::new(name.span(), message));
+                        }
+                    }
+                }
+                let variant = Ident::new(variant, name.span());
+                let attrs = expand_attrs(attrs);
+                Ok(quote! {
+                    ::sauron_native::widget::widget(
+                        ::sauron_native::Widget::#variant(#(#data),*),
+                        #attrs,
+                        ::std::vec::Vec::new(),
+                    )
+                })
+            }
+        }
+    }
+}
+
+fn no_children(name: &Ident, children: &[Child]) -> Result<()> {
+    if children.is_empty() {
+        Ok(())
+    } else {
+        let message = format!("`<{}>` can not have children", name);
+        Err(syn::Error::new(name.span(), message))
+    }
+}
+
+/// each attribute calls the attribute function of its name,
+/// so an unknown attribute is reported at its name.
+/// The `icon` of a button is set with `icon_name`, since `icon` is also the icon widget
+fn expand_attrs(attrs: Vec<Attr>) -> TokenStream {
+    let attrs = attrs.into_iter().map(|Attr { name, value }| {
+        let function = if name == "icon" {
+            Ident::new("icon_name", name.span())
+        } else {
+            name
+        };
+        quote_spanned! { function.span()=>
+            ::sauron_native::widget::attribute::#function(#value)
+        }
+    });
+    quote! { vec![#(#attrs),*] }
+}
+
+fn expand_children(children: Vec<Child>) -> Result<TokenStream> {
+    let pushes = children
+        .into_iter()
+        .map(|child| match child {
+            Child::Element(element) => {
+                let node = element.expand()?;
+                Ok(quote! { __children.push(#node); })
+            }
+            Child::Text(txt) => {
+                Ok(quote! { __children.push(::sauron_native::widget::text(#txt)); })
+            }
+            Child::Block(expr) => Ok(quote! {
+                __children.extend(::sauron_native::widget::IntoNodes::into_nodes(#expr));
+            }),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            let mut __children = ::std::vec::Vec::new();
+            #(#pushes)*
+            __children
+        }
+    })
+}
@@ -0,0 +1,36 @@
+use sauron_native::{
+    view,
+    widget::{
+        attribute::{disabled, icon_name, label, spacing},
+        button, checkbox, column,
+        icon::Icon,
+        text, text_with,
+    },
+    Node,
+};
+
+#[test]
+fn expands_to_the_builders() {
+    let entries = vec!["first", "second"];
+    let expanded: Node<()> = view! {
+        <column spacing=8.0>
+            <text>"What needs to be done?"</text>
+            <checkbox label="Done" disabled />
+            <button label="Save" icon={Icon::Save} />
+            { entries.iter().map(|entry| text(entry)).collect::<Vec<_>>() }
+            "The end"
+        </column>
+    };
+    let built: Node<()> = column(
+        vec![spacing(8.0)],
+        vec![
+            text_with(vec![], "What needs to be done?"),
+            checkbox(vec![label("Done"), disabled(true)]),
+            button(vec![label("Save"), icon_name(Icon::Save)]),
+            text("first"),
+            text("second"),
+            text("The end"),
+        ],
+    );
+    assert_eq!(expanded, built);
+}
@@ -0,0 +1,5 @@
+use sauron_native::{view, Node};
+
+fn main() {
+    let _: Node<()> = view! { <button labl="Save" /> };
+}
@@ -1,19 +1,23 @@
 use log::*;
 use sauron_native::{
     event::{on, InputEvent},
+    view,
     widget::{attribute::*, *},
-    Attribute, Callback, Component, Event, Node, Program, Value,
+    Attribute, Callback, Cmd, Component, Event, Node, Program, Value,
 };
 use std::{
     cell::{Cell, RefCell},
     rc::Rc,
 };
 
+use crate::counter::{self, Counter};
+
 pub struct App {
     click_count: u32,
     text: String,
     events: Vec<String>,
     debug: Vec<String>,
+    counter: Counter,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +25,7 @@ pub enum Msg {
     Click,
     ChangeText(String),
     Decrement,
+    Counter(counter::Msg),
 }
 
 impl App {
@@ -33,19 +38,22 @@ impl App {
             text: String::from("Some text"),
             events: vec![],
             debug: vec![],
+            counter: Counter::default(),
         }
     }
 }
 
 impl Component<Msg> for App {
-    fn update(&mut self, msg: Msg) {
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
         match msg {
             Msg::Click => self.click_count += 1,
             Msg::Decrement => self.click_count -= 1,
             Msg::ChangeText(txt) => {
                 self.text = txt;
             }
+            Msg::Counter(msg) => return self.counter.update(msg).map_msg(Msg::Counter),
         }
+        Cmd::none()
     }
 
     fn view(&self) -> Node<Msg> {
@@ -63,16 +71,16 @@ impl Component<Msg> for App {
                     on_click(|_| Msg::Click),
                     label(format!("Hello: {}", self.click_count)),
                 ]),
-                column(
-                    vec![],
-                    vec![
-                        checkbox(vec![label("Checkbox1"), value(true)]),
-                        checkbox(vec![label("Checkbox2"), value(false)]),
-                        checkbox(vec![label("Checkbox3"), value(false)]),
-                        radio(vec![label("Radio1"), value(true)]),
-                        radio(vec![label("Radio2"), value(false)]),
-                    ],
-                ),
+                self.counter.view().map_msg(Msg::Counter),
+                view! {
+                    <column>
+                        <checkbox label="Checkbox1" value=true />
+                        <checkbox label="Checkbox2" value=false />
+                        <checkbox label="Checkbox3" value=false />
+                        <radio label="Radio1" value=true />
+                        <radio label="Radio2" value=false />
+                    </column>
+                },
                 row(vec![], {
                     (0..self.click_count)
                         .map(|x| button(vec![label("Hello".to_string())]))
@@ -88,7 +96,10 @@ impl Component<Msg> for App {
                         }
                     }),
                 ]),
-                image(include_bytes!("../horse.jpg").to_vec()),
+                image_with(
+                    vec![accessible_label("A horse")],
+                    include_bytes!("../horse.jpg").to_vec(),
+                ),
                 text("This is a paragraph"),
             ],
         )
@@ -0,0 +1,41 @@
+//! A counter with its own model, update and view,
+//! embedded in the app with its messages mapped into those of the app
+use sauron_native::{
+    widget::{attribute::*, *},
+    Cmd, Component, Node,
+};
+
+#[derive(Default)]
+pub struct Counter {
+    count: i32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Msg {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+impl Component<Msg> for Counter {
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Increment => self.count += 1,
+            Msg::Decrement => self.count -= 1,
+            Msg::Reset => self.count = 0,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        row(
+            vec![],
+            vec![
+                button(vec![on_click(|_| Msg::Decrement), label("-")]),
+                text(&format!("Count: {}", self.count)),
+                button(vec![on_click(|_| Msg::Increment), label("+")]),
+                button(vec![on_click(|_| Msg::Reset), label("Reset")]),
+            ],
+        )
+    }
+}
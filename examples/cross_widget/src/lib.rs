@@ -8,6 +8,7 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 pub mod app;
+pub mod counter;
 
 #[cfg(feature = "with-html")]
 #[wasm_bindgen]
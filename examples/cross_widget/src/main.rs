@@ -10,6 +10,7 @@ use sauron_native::Program;
 use std::rc::Rc;
 
 pub mod app;
+pub mod counter;
 use app::{App, Msg};
 
 fn main() {
@@ -1,4 +1,14 @@
-use sauron_native::{event::on, util::*, widget::*, Component, Event, Node, Program};
+use sauron_native::{
+    event::on,
+    style::{rule, Selector, StyleSheet},
+    util::*,
+    widget::{
+        attribute::{autofocus, class, color, on_blur, on_double_click, on_key_press, placeholder},
+        property::Color,
+        *,
+    },
+    Cmd, Component, Event, Node, Program,
+};
 
 pub struct Model {
     entries: Vec<Entry>,
@@ -40,7 +50,7 @@ impl Model {
 }
 
 impl Component<Msg> for Model {
-    fn update(&mut self, msg: Msg) {
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
         match msg {
             Msg::Add => {
                 let entry = Entry {
@@ -86,6 +96,14 @@ impl Component<Msg> for Model {
             }
             Msg::Nope => {}
         }
+        Cmd::none()
+    }
+
+    fn style_sheet(&self) -> StyleSheet {
+        StyleSheet::new(vec![
+            rule(Selector::class("completed"), vec![color(Color::rgb(217, 217, 217))]),
+            rule(Selector::class("editing"), vec![color(Color::rgb(77, 77, 77))]),
+        ])
     }
 
     fn view(&self) -> Node<Msg> {
@@ -150,19 +168,15 @@ impl Model {
         textbox(
             vec![
                 value(self.value.to_string()),
+                placeholder("What needs to be done?"),
                 oninput(|e: Event| match e {
                     Event::InputEvent(v) => Msg::Update(v.value),
                     _ => panic!("expecting input event"),
                 }),
-                /*
-                onkeypress(|event: KeyEvent| {
-                    if event.key == "Enter" {
-                        Msg::Add
-                    } else {
-                        Msg::Nope
-                    }
+                on_key_press(|event: Event| match event {
+                    Event::KeyEvent(key_event) if key_event.key == "Enter" => Msg::Add,
+                    _ => Msg::Nope,
                 }),
-                */
             ],
             &self.value.to_string(),
         )
@@ -178,7 +192,7 @@ fn view_entry((idx, entry): (usize, &Entry)) -> Node<Msg> {
         class_name.push_str(" completed");
     }
     vbox(
-        vec![],
+        vec![class(class_name)],
         vec![
             vbox(
                 vec![],
@@ -192,12 +206,12 @@ fn view_entry((idx, entry): (usize, &Entry)) -> Node<Msg> {
                         ],
                         vec![],
                     ),
-                    label(
-                        vec![ondoubleclick(move |_| Msg::ToggleEdit(idx))],
-                        vec![text(&format!("{}", entry.description))],
-                    ),
                     button(vec![onclick(move |_| Msg::Remove(idx))], vec![]),
                 */
+                    text_with(
+                        vec![on_double_click(move |_| Msg::ToggleEdit(idx))],
+                        &entry.description,
+                    ),
                 ],
             ),
             { view_entry_edit_input((idx, &entry)) },
@@ -214,16 +228,12 @@ fn view_entry_edit_input((idx, entry): (usize, &Entry)) -> Node<Msg> {
                     Event::InputEvent(input) => Msg::UpdateEdit(input.value),
                     _ => panic!("expecting an input event"),
                 }),
-                /*
-                onblur(move |_| Msg::Edit(idx)),
-                onkeypress(move |event: KeyEvent| {
-                    if event.key == "Enter" {
-                        Msg::Edit(idx)
-                    } else {
-                        Msg::Nope
-                    }
+                autofocus(true),
+                on_blur(move |_| Msg::Edit(idx)),
+                on_key_press(move |event: Event| match event {
+                    Event::KeyEvent(key_event) if key_event.key == "Enter" => Msg::Edit(idx),
+                    _ => Msg::Nope,
                 }),
-                */
             ],
             &entry.description,
         )
@@ -1,3 +1,12 @@
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+use crate::{widget::attribute::find_callback, Callback, Element, Event};
+#[cfg(any(
+    feature = "with-tui",
+    feature = "with-titik",
+    feature = "with-gtk",
+    feature = "with-html"
+))]
+use crate::{widget::attribute::attr, AttribKey};
 use crate::{Component, Node, Widget};
 use std::{fmt::Debug, rc::Rc};
 
@@ -6,6 +15,15 @@ pub mod html;
 #[cfg(feature = "with-html")]
 pub use html::HtmlBackend;
 
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+mod executor;
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+mod focus;
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+mod screen_reader;
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+mod timers;
+
 #[cfg(feature = "with-tui")]
 pub mod text_ui;
 
@@ -18,6 +36,12 @@ pub mod gtk_ui;
 #[cfg(feature = "with-nwg")]
 pub mod nwg_ui;
 
+/// the size of a terminal cell, used to convert pixel lengths into cells
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+pub(crate) const PIXELS_PER_COLUMN: f64 = 8.0;
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+pub(crate) const PIXELS_PER_ROW: f64 = 16.0;
+
 pub trait Backend<APP, MSG>
 where
     MSG: 'static,
@@ -31,3 +55,45 @@ where
         // this is useful for tui backend
     }
 }
+
+/// set a state such as `Hovered` on the element at this path of child indices,
+/// before the style sheet is applied so its rules select the state
+#[cfg(any(
+    feature = "with-tui",
+    feature = "with-titik",
+    feature = "with-gtk",
+    feature = "with-html"
+))]
+pub(crate) fn mark_state<MSG>(node: &mut Node<MSG>, path: &[usize], key: AttribKey)
+where
+    MSG: 'static,
+{
+    if let Node::Element(element) = node {
+        match path.split_first() {
+            None => element.attrs.push(attr(key, true)),
+            Some((index, rest)) => {
+                if let Some(child) = element.children.get_mut(*index) {
+                    mark_state(child, rest, key);
+                }
+            }
+        }
+    }
+}
+
+/// the callback of this event on the focused widget, or on the root widget when
+/// none has the focus, used by the terminal backends to deliver the key events
+#[cfg(any(feature = "with-tui", feature = "with-titik"))]
+pub(crate) fn target_callback<'v, MSG>(
+    key: AttribKey,
+    view: &'v Node<MSG>,
+    focused: Option<&'v Element<MSG>>,
+) -> Option<&'v Callback<Event, MSG>>
+where
+    MSG: 'static,
+{
+    let target = match (focused, view) {
+        (Some(element), _) | (None, Node::Element(element)) => element,
+        (None, Node::Text(_)) => return None,
+    };
+    find_callback(key, &target.attrs)
+}
@@ -0,0 +1,105 @@
+//! A small executor of the futures performed by the commands in the terminal backends.
+//! The futures are polled on the ui thread, again every time one of them is woken up.
+use futures::task::{self, ArcWake};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+type Task<MSG> = Pin<Box<dyn Future<Output = MSG>>>;
+
+pub struct Executor<MSG> {
+    tasks: Vec<Task<MSG>>,
+    wake: Arc<Wake>,
+}
+
+struct Wake {
+    woken: AtomicBool,
+    /// tells the draw loop to poll the tasks, it may be called from any thread
+    notify: Box<dyn Fn() + Send + Sync>,
+}
+
+impl ArcWake for Wake {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.woken.store(true, Ordering::SeqCst);
+        (arc_self.notify)();
+    }
+}
+
+impl<MSG> Executor<MSG> {
+    pub fn new<F>(notify: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        Executor {
+            tasks: vec![],
+            wake: Arc::new(Wake {
+                woken: AtomicBool::new(false),
+                notify: Box::new(notify),
+            }),
+        }
+    }
+
+    /// the task is woken up to be polled for the first time
+    pub fn spawn(&mut self, task: Task<MSG>) {
+        self.tasks.push(task);
+        ArcWake::wake_by_ref(&self.wake);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// poll the tasks if any was woken up, returns the messages of those which completed
+    pub fn poll(&mut self) -> Vec<MSG> {
+        if !self.wake.woken.swap(false, Ordering::SeqCst) {
+            return vec![];
+        }
+        let waker = task::waker(Arc::clone(&self.wake));
+        let mut context = Context::from_waker(&waker);
+        let mut messages = vec![];
+        let mut pending = vec![];
+        for mut task in self.tasks.drain(..) {
+            match task.as_mut().poll(&mut context) {
+                Poll::Ready(msg) => messages.push(msg),
+                Poll::Pending => pending.push(task),
+            }
+        }
+        self.tasks = pending;
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn poll_the_woken_tasks() {
+        let notified = Arc::new(AtomicUsize::new(0));
+        let mut executor = {
+            let notified = Arc::clone(&notified);
+            Executor::new(move || {
+                notified.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        let (tx, rx) = oneshot::channel();
+        executor.spawn(Box::pin(async { 1 }));
+        executor.spawn(Box::pin(async move { rx.await.unwrap_or(0) }));
+        assert_eq!(notified.load(Ordering::SeqCst), 2);
+        assert_eq!(executor.poll(), vec![1]);
+        // nothing is woken up until the value is sent
+        assert!(executor.poll().is_empty());
+        assert!(!executor.is_empty());
+        tx.send(2).unwrap();
+        assert_eq!(executor.poll(), vec![2]);
+        assert!(executor.is_empty());
+    }
+}
@@ -0,0 +1,353 @@
+//! The focus ring of the terminal backends, the widgets which take the focus
+//! are traversed with Tab and Shift-Tab in the order they appear in the view.
+use crate::{
+    widget::attribute::{find_callback, find_value},
+    AttribKey, Callback, Element, Event, Node, Widget,
+};
+use sauron_vdom::event::{InputEvent, KeyEvent, MouseEvent};
+use std::cell::RefCell;
+
+fn is_focusable(widget: &Widget) -> bool {
+    matches!(
+        widget,
+        Widget::Button | Widget::TextInput | Widget::Checkbox | Widget::Radio | Widget::Expander
+    )
+}
+
+fn flag<MSG>(element: &Element<MSG>, key: AttribKey) -> Option<bool>
+where
+    MSG: 'static,
+{
+    find_value(key, &element.attrs)
+        .map(|v| v.as_bool())
+        .flatten()
+}
+
+/// the disabled and hidden widgets are skipped along with their children,
+/// and so are the children of a collapsed expander which are not drawn
+fn is_skipped<MSG>(element: &Element<MSG>) -> bool
+where
+    MSG: 'static,
+{
+    flag(element, AttribKey::Disabled) == Some(true)
+        || flag(element, AttribKey::Visible) == Some(false)
+}
+
+fn shows_children<MSG>(element: &Element<MSG>) -> bool
+where
+    MSG: 'static,
+{
+    element.tag != Widget::Expander || flag(element, AttribKey::Expanded) == Some(true)
+}
+
+/// a widget which takes the focus, with its path of child indices in the view
+pub struct Focusable<'v, MSG> {
+    pub path: Vec<usize>,
+    pub element: &'v Element<MSG>,
+}
+
+/// the widgets which take the focus, in the order of the traversal
+pub fn focus_ring<MSG>(node: &Node<MSG>) -> Vec<Focusable<MSG>>
+where
+    MSG: 'static,
+{
+    fn collect<'v, MSG>(
+        node: &'v Node<MSG>,
+        path: &mut Vec<usize>,
+        ring: &mut Vec<Focusable<'v, MSG>>,
+    ) where
+        MSG: 'static,
+    {
+        if let Node::Element(element) = node {
+            if is_skipped(element) {
+                return;
+            }
+            if is_focusable(&element.tag) {
+                ring.push(Focusable {
+                    path: path.clone(),
+                    element,
+                });
+            }
+            if shows_children(element) {
+                for (index, child) in element.children.iter().enumerate() {
+                    path.push(index);
+                    collect(child, path, ring);
+                    path.pop();
+                }
+            }
+        }
+    }
+    let mut ring = vec![];
+    collect(node, &mut vec![], &mut ring);
+    ring
+}
+
+/// the index in the focus ring of the widget with this id
+pub fn find_by_id<MSG>(ring: &[Focusable<MSG>], id: &str) -> Option<usize>
+where
+    MSG: 'static,
+{
+    ring.iter()
+        .position(|focusable| id_of(focusable.element).as_deref() == Some(id))
+}
+
+pub fn find_autofocus<MSG>(ring: &[Focusable<MSG>]) -> Option<usize>
+where
+    MSG: 'static,
+{
+    ring.iter()
+        .position(|focusable| flag(focusable.element, AttribKey::Autofocus) == Some(true))
+}
+
+fn id_of<MSG>(element: &Element<MSG>) -> Option<String>
+where
+    MSG: 'static,
+{
+    find_value(AttribKey::Id, &element.attrs).map(|v| v.to_string())
+}
+
+fn value_of<MSG>(element: &Element<MSG>) -> String
+where
+    MSG: 'static,
+{
+    find_value(AttribKey::Value, &element.attrs)
+        .map(|v| v.to_string())
+        .unwrap_or_default()
+}
+
+/// the focus or blur event of the widget, with the value of the widget
+pub fn focus_event<MSG>(element: &Element<MSG>, key: AttribKey) -> Option<MSG>
+where
+    MSG: 'static,
+{
+    find_callback(key, &element.attrs).map(|cb| cb.emit(InputEvent::new(value_of(element))))
+}
+
+/// pressing Space or Enter on the focused widget clicks a button,
+/// and toggles a checkbox, a radio or an expander
+pub fn activate<MSG>(element: &Element<MSG>) -> Option<MSG>
+where
+    MSG: 'static,
+{
+    match element.tag {
+        Widget::Button => find_callback(AttribKey::ClickEvent, &element.attrs).map(|cb| {
+            cb.emit(MouseEvent {
+                r#type: "click",
+                ..Default::default()
+            })
+        }),
+        Widget::Checkbox | Widget::Radio | Widget::Expander => {
+            let state_key = match element.tag {
+                Widget::Expander => AttribKey::Expanded,
+                _ => AttribKey::Value,
+            };
+            let state = flag(element, state_key).unwrap_or(false);
+            // a radio stays checked when it is activated again
+            let new_state = element.tag == Widget::Radio || !state;
+            find_callback(AttribKey::ToggleEvent, &element.attrs)
+                .map(|cb| cb.emit(InputEvent::new(new_state.to_string())))
+        }
+        _ => None,
+    }
+}
+
+/// the focused widget is known by its id, so it keeps the focus when the widgets
+/// before it change, or else by its path in the view
+#[derive(Clone, Debug, PartialEq)]
+enum FocusKey {
+    Id(String),
+    Path(Vec<usize>),
+}
+
+impl FocusKey {
+    fn of<MSG>(focusable: &Focusable<MSG>) -> Self
+    where
+        MSG: 'static,
+    {
+        match id_of(focusable.element) {
+            Some(id) => FocusKey::Id(id),
+            None => FocusKey::Path(focusable.path.clone()),
+        }
+    }
+}
+
+/// the widget which has the focus in a terminal backend
+pub struct Focus<MSG> {
+    focused: RefCell<Option<FocusKey>>,
+    /// the blur event of the focused widget with its value,
+    /// which is emitted even when the widget is gone from the view
+    blur: RefCell<Option<(Callback<Event, MSG>, String)>>,
+    /// the id of the widget which was last requested to have the focus
+    request: RefCell<Option<String>>,
+}
+
+impl<MSG> Focus<MSG>
+where
+    MSG: 'static,
+{
+    pub fn new() -> Self {
+        Focus {
+            focused: RefCell::new(None),
+            blur: RefCell::new(None),
+            request: RefCell::new(None),
+        }
+    }
+
+    /// the index in the focus ring of the focused widget
+    fn position(&self, ring: &[Focusable<MSG>]) -> Option<usize> {
+        let focused = self.focused.borrow();
+        let key = focused.as_ref()?;
+        ring.iter().position(|focusable| FocusKey::of(focusable) == *key)
+    }
+
+    /// the path of the focused widget in the view
+    pub fn focused_path(&self, view: &Node<MSG>) -> Option<Vec<usize>> {
+        let ring = focus_ring(view);
+        self.position(&ring).map(|index| ring[index].path.clone())
+    }
+
+    pub fn focused_element<'v>(&self, view: &'v Node<MSG>) -> Option<&'v Element<MSG>> {
+        let ring = focus_ring(view);
+        self.position(&ring).map(|index| ring[index].element)
+    }
+
+    /// blur the focused widget once it is gone from the view, move the focus as requested
+    /// by the app, and to the autofocus widget when none has the focus.
+    /// Returns the messages of the blur and focus events
+    pub fn update(&self, view: &Node<MSG>, request: Option<String>) -> Vec<MSG> {
+        let ring = focus_ring(view);
+        let mut messages = vec![];
+        let position = self.position(&ring);
+        if self.focused.borrow().is_some() && position.is_none() {
+            messages.extend(self.move_focus(&ring, None));
+        }
+        if *self.request.borrow() != request {
+            if let Some(id) = &request {
+                messages.extend(self.move_focus(&ring, find_by_id(&ring, id)));
+            }
+            *self.request.borrow_mut() = request;
+        }
+        if self.focused.borrow().is_none() {
+            if let Some(autofocus) = find_autofocus(&ring) {
+                messages.extend(self.move_focus(&ring, Some(autofocus)));
+            }
+        }
+        // the blur event is kept up to date with the value of the focused widget
+        if let Some(index) = self.position(&ring) {
+            *self.blur.borrow_mut() = blur_of(ring[index].element);
+        }
+        messages
+    }
+
+    /// focus the widget with this id, as requested by a command
+    pub fn focus_id(&self, view: &Node<MSG>, id: &str) -> Vec<MSG> {
+        let ring = focus_ring(view);
+        self.move_focus(&ring, find_by_id(&ring, id))
+    }
+
+    /// Tab and Shift-Tab move the focus along the focus ring,
+    /// Space and Enter activate the focused widget.
+    /// Returns the messages and whether the key is a Tab used up for the traversal
+    pub fn handle_key(&self, view: &Node<MSG>, key_event: &KeyEvent) -> (Vec<MSG>, bool) {
+        let ring = focus_ring(view);
+        match key_event.key.as_str() {
+            "Tab" if !ring.is_empty() => {
+                let last = ring.len() - 1;
+                let next = match (self.position(&ring), key_event.modifier.shift_key) {
+                    (None, false) => 0,
+                    (None, true) => last,
+                    (Some(focused), false) => (focused + 1) % ring.len(),
+                    (Some(focused), true) => focused.checked_sub(1).unwrap_or(last),
+                };
+                (self.move_focus(&ring, Some(next)), true)
+            }
+            " " | "Enter" => {
+                let activated = self
+                    .position(&ring)
+                    .and_then(|focused| activate(ring[focused].element));
+                (activated.into_iter().collect(), false)
+            }
+            _ => (vec![], false),
+        }
+    }
+
+    /// emits the blur event of the widget losing the focus
+    /// and the focus event of the widget taking it
+    fn move_focus(&self, ring: &[Focusable<MSG>], new_focus: Option<usize>) -> Vec<MSG> {
+        let new_key = new_focus.map(|index| FocusKey::of(&ring[index]));
+        if *self.focused.borrow() == new_key {
+            return vec![];
+        }
+        let mut messages = vec![];
+        if let Some((cb, value)) = self.blur.borrow_mut().take() {
+            messages.push(cb.emit(InputEvent::new(value)));
+        }
+        *self.focused.borrow_mut() = new_key;
+        if let Some(index) = new_focus {
+            let element = ring[index].element;
+            *self.blur.borrow_mut() = blur_of(element);
+            messages.extend(focus_event(element, AttribKey::FocusEvent));
+        }
+        messages
+    }
+}
+
+fn blur_of<MSG>(element: &Element<MSG>) -> Option<(Callback<Event, MSG>, String)>
+where
+    MSG: 'static,
+{
+    find_callback(AttribKey::BlurEvent, &element.attrs).map(|cb| (cb.clone(), value_of(element)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{
+        attribute::{disabled, expanded, id, on_click, on_toggle, value, visible},
+        button, checkbox, column, expander, radio, text_input,
+    };
+
+    fn toggled(event: Event) -> String {
+        match event {
+            Event::InputEvent(input) => input.value,
+            _ => String::new(),
+        }
+    }
+
+    #[test]
+    fn focus_ring_skips_what_is_not_shown() {
+        let view: Node<()> = column(
+            vec![],
+            vec![
+                button(vec![id("save")]),
+                button(vec![disabled(true)]),
+                text_input(vec![visible(false)]),
+                expander(vec![expanded(false)], vec![checkbox(vec![])]),
+                expander(vec![expanded(true)], vec![checkbox(vec![id("check")])]),
+            ],
+        );
+        let ring = focus_ring(&view);
+        let paths: Vec<Vec<usize>> = ring.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(paths, vec![vec![0], vec![3], vec![4], vec![4, 0]]);
+        assert_eq!(find_by_id(&ring, "check"), Some(3));
+        assert_eq!(find_by_id(&ring, "missing"), None);
+    }
+
+    #[test]
+    fn activate_toggles_the_state() {
+        let unchecked: Node<String> = checkbox(vec![value(false), on_toggle(toggled)]);
+        let checked: Node<String> = checkbox(vec![value(true), on_toggle(toggled)]);
+        let selected: Node<String> = radio(vec![value(true), on_toggle(toggled)]);
+        let collapsed: Node<String> = expander(vec![on_toggle(toggled)], vec![]);
+        let save: Node<String> = button(vec![on_click(|_| "clicked".to_string())]);
+        let activated = |node: &Node<String>| match node {
+            Node::Element(element) => activate(element),
+            _ => None,
+        };
+        assert_eq!(activated(&unchecked), Some("true".to_string()));
+        assert_eq!(activated(&checked), Some("false".to_string()));
+        assert_eq!(activated(&selected), Some("true".to_string()));
+        assert_eq!(activated(&collapsed), Some("true".to_string()));
+        assert_eq!(activated(&save), Some("clicked".to_string()));
+    }
+}
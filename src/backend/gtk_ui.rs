@@ -1,24 +1,41 @@
-use crate::{Backend, Component, Widget};
+use super::mark_state;
+use crate::{cmd::Command, sub::Source, Backend, Cmd, Component, Sub, Theme, Widget};
 use gdk_pixbuf::{PixbufLoader, PixbufLoaderExt};
 use gio::{prelude::*, ApplicationFlags};
-use glib::Value;
+use glib::{object::ObjectType, Value};
 use gtk::{
-    prelude::*, Application, ApplicationWindow, Button, CheckButton, Container, CssProvider, Entry,
-    EntryBuffer, Image, Orientation, RadioButton, StyleContext, TextBuffer, TextBufferExt,
-    TextTagTable, TextView, WidgetExt, Window, WindowPosition, WindowType,
+    prelude::*, Align, Application, ApplicationWindow, Button, CheckButton, Container, CssProvider,
+    DrawingArea, Entry, EntryBuffer, Expander, Frame, IconSize, Image, Inhibit, Justification,
+    Orientation, PackType, RadioButton, Separator, ShadowType, StyleContext, TextBuffer,
+    TextBufferExt, TextTagTable, TextView, ToggleButton, WidgetExt, Window, WindowPosition,
+    WindowType,
+};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    marker::PhantomData,
+    rc::Rc,
+    time::{Duration, Instant},
 };
-use std::{fmt::Debug, marker::PhantomData, rc::Rc};
 
 use crate::{
-    widget::attribute::{find_callback, find_value},
+    widget::{
+        attribute::{find_callback, find_value},
+        chart::Chart,
+        icon::{self, Icon},
+        property::{self, Alignment, BorderStyle, Color},
+        sizing::{Length, Sizing},
+    },
     AttribKey, Attribute, Node, Patch,
 };
+use atk::prelude::*;
 use gtk::{IsA, Label, Paned};
+use gdk::{EventMask, EventType, ModifierType, ScrollDirection};
 use sauron_vdom::{
-    event::{InputEvent, MouseEvent},
+    event::{Coordinate, InputEvent, KeyEvent, Modifier, MouseButton, MouseEvent},
     AttribValue, Dispatch,
 };
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 mod apply_patches;
 
@@ -30,20 +47,45 @@ where
     current_vdom: Rc<RefCell<Node<MSG>>>,
     root_node: Rc<RefCell<Option<GtkWidget>>>,
     application: Application,
+    /// holds the css generated from the theme of the app
+    css_provider: CssProvider,
+    current_theme: RefCell<Option<Theme>>,
+    /// the id of the widget which was last requested to have the focus
+    focus: RefCell<Option<String>>,
+    /// the subscriptions of the app, read by the running sources when they fire
+    subscriptions: RefCell<Sub<MSG>>,
+    /// the timers started for the subscriptions
+    sources: RefCell<HashMap<Source, glib::SourceId>>,
+    /// the path of child indices to the widget under the pointer
+    hovered: RefCell<Option<Vec<usize>>>,
     _phantom_msg: PhantomData<MSG>,
 }
+
+/// gtk 0.6 has no frame clock callback, so the frames are timed at 60 per second
+const ANIMATION_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// the name of the empty boxes which take the free space between the children
+/// of a box, they are not in the view so they are skipped when patching
+const SPACER_NAME: &str = "sauron-native-spacer";
+
 impl<APP, MSG> GtkBackend<APP, MSG>
 where
     MSG: Debug + 'static,
     APP: Component<MSG> + 'static,
 {
     fn new(app: APP) -> Rc<Self> {
-        let current_vdom = app.view();
-        let root_vdom = app.view();
+        let style_sheet = app.style_sheet();
+        let current_vdom = style_sheet.apply(app.view());
+        let root_vdom = style_sheet.apply(app.view());
+        let theme = app.theme();
+        let focus = app.focus();
+        let subscriptions = app.subscriptions();
 
         if gtk::init().is_err() {
             println!("failed to initialize GTK Application");
         }
+        let css_provider = CssProvider::new();
+        load_theme(&css_provider, theme.as_ref());
         let root_widget: Option<GtkWidget> = None;
         let mut backend = GtkBackend {
             app: Rc::new(RefCell::new(app)),
@@ -51,10 +93,16 @@ where
             root_node: Rc::new(RefCell::new(root_widget)),
             application: Application::new("ivanceras.github.io.gtk", ApplicationFlags::FLAGS_NONE)
                 .expect("Failed to start app"),
+            css_provider,
+            current_theme: RefCell::new(theme),
+            focus: RefCell::new(focus),
+            subscriptions: RefCell::new(subscriptions),
+            sources: RefCell::new(HashMap::new()),
+            hovered: RefCell::new(None),
             _phantom_msg: PhantomData,
         };
         let rc_backend = Rc::new(backend);
-        let root_widget = Self::from_node_tree(&rc_backend, root_vdom);
+        let root_widget = Self::from_node_tree(&rc_backend, root_vdom, &mut vec![]);
         *rc_backend.root_node.borrow_mut() = Some(root_widget);
         rc_backend
     }
@@ -79,15 +127,170 @@ where
         MSG: Debug,
     {
         println!("dispatching : {:?}", msg);
-        self.app.borrow_mut().update(msg);
-        let new_view = self.app.borrow().view();
+        let cmd = self.app.borrow_mut().update(msg);
+        self.update_view(cmd);
+    }
+
+    /// patch the view changed by an update and execute the command it returned
+    fn update_view(self: &Rc<Self>, cmd: Cmd<MSG>)
+    where
+        MSG: Debug,
+    {
+        let new_theme = self.app.borrow().theme();
+        if *self.current_theme.borrow() != new_theme {
+            load_theme(&self.css_provider, new_theme.as_ref());
+            *self.current_theme.borrow_mut() = new_theme;
+        }
+        let new_view = {
+            let app = self.app.borrow();
+            let mut view = app.view();
+            if let Some(path) = &*self.hovered.borrow() {
+                mark_state(&mut view, path, AttribKey::Hovered);
+            }
+            app.style_sheet().apply(view)
+        };
         {
             let current_vdom = self.current_vdom.borrow();
             let diff = sauron_vdom::diff_with_key(&current_vdom, &new_view, &AttribKey::Key);
             println!("diff: {:#?}", diff);
-            apply_patches::apply_patches(&self.root_container(), &diff);
+            apply_patches::apply_patches(&self.root_container(), &current_vdom, &diff);
         }
         *self.current_vdom.borrow_mut() = new_view;
+        let focus = self.app.borrow().focus();
+        if *self.focus.borrow() != focus {
+            if let Some(id) = &focus {
+                self.grab_focus(id);
+            }
+            *self.focus.borrow_mut() = focus;
+        }
+        *self.subscriptions.borrow_mut() = self.app.borrow().subscriptions();
+        self.sync_subscriptions();
+        self.execute(cmd);
+    }
+
+    /// the view is updated with the new hovered widget, so the style sheet can select it
+    fn set_hovered(self: &Rc<Self>, path: Option<Vec<usize>>)
+    where
+        MSG: Debug,
+    {
+        if *self.hovered.borrow() != path {
+            *self.hovered.borrow_mut() = path;
+            self.update_view(Cmd::none());
+        }
+    }
+
+    /// the pointer entering the widget makes it the hovered widget, leaving it unsets it
+    /// unless the pointer has already entered another widget
+    fn connect_hover(self: &Rc<Self>, widget: &gtk::Widget, path: Vec<usize>)
+    where
+        MSG: Debug,
+    {
+        widget.add_events(EventMask::ENTER_NOTIFY_MASK | EventMask::LEAVE_NOTIFY_MASK);
+        let self_clone = Rc::clone(self);
+        let entered = path.clone();
+        widget.connect_enter_notify_event(move |_, _| {
+            self_clone.set_hovered(Some(entered.clone()));
+            Inhibit(false)
+        });
+        let self_clone = Rc::clone(self);
+        widget.connect_leave_notify_event(move |_, _| {
+            let left = self_clone.hovered.borrow().as_ref() == Some(&path);
+            if left {
+                self_clone.set_hovered(None);
+            }
+            Inhibit(false)
+        });
+    }
+
+    /// start the timers of the new subscriptions and stop those no longer subscribed to
+    fn sync_subscriptions(self: &Rc<Self>) {
+        let subscribed = self.subscriptions.borrow().sources();
+        let mut sources = self.sources.borrow_mut();
+        let stopped: Vec<Source> = sources
+            .keys()
+            .filter(|source| !subscribed.contains(source))
+            .cloned()
+            .collect();
+        for source in stopped {
+            if let Some(source_id) = sources.remove(&source) {
+                glib::source_remove(source_id);
+            }
+        }
+        for source in subscribed {
+            if sources.contains_key(&source) {
+                continue;
+            }
+            let self_clone = Rc::clone(self);
+            let source_id = match source {
+                Source::Every(interval) => {
+                    // a zero interval would fire on every iteration of the main loop
+                    let millis = (interval.as_millis() as u32).max(1);
+                    glib::timeout_add_local(millis, move || {
+                        let messages = self_clone.subscriptions.borrow().every_messages(interval);
+                        for msg in messages {
+                            self_clone.dispatch_inner(msg);
+                        }
+                        glib::Continue(true)
+                    })
+                }
+                Source::AnimationFrame => {
+                    let mut last_frame = Instant::now();
+                    let interval = ANIMATION_FRAME_INTERVAL.as_millis() as u32;
+                    glib::timeout_add_local(interval, move || {
+                        let now = Instant::now();
+                        let elapsed = now.duration_since(last_frame).as_secs_f64() * 1000.0;
+                        last_frame = now;
+                        let messages = self_clone
+                            .subscriptions
+                            .borrow()
+                            .animation_frame_messages(elapsed);
+                        for msg in messages {
+                            self_clone.dispatch_inner(msg);
+                        }
+                        glib::Continue(true)
+                    })
+                }
+                // the window emits the resize messages from the current subscriptions
+                Source::Resize => continue,
+            };
+            sources.insert(source, source_id);
+        }
+    }
+
+    /// execute the commands of an update, once the view is patched
+    fn execute(self: &Rc<Self>, cmd: Cmd<MSG>)
+    where
+        MSG: Debug,
+    {
+        for command in cmd.into_commands() {
+            match command {
+                Command::Effect(effect) => {
+                    if let Some(msg) = effect() {
+                        self.dispatch_inner(msg);
+                    }
+                }
+                Command::Perform(future) => {
+                    let self_clone = Rc::clone(self);
+                    glib::MainContext::default().spawn_local(async move {
+                        let msg = future.await;
+                        self_clone.dispatch_inner(msg);
+                    });
+                }
+                Command::Quit => self.application.quit(),
+                Command::Focus(id) => self.grab_focus(&id),
+                // the widgets are not put in a scrolled window
+                Command::ScrollTo(_) => (),
+            }
+        }
+    }
+
+    /// the widget is found by its name, which is set from its id
+    fn grab_focus(self: &Rc<Self>, id: &str) {
+        let root_container = self.root_container();
+        match find_widget_by_name(&root_container, id) {
+            Some(widget) => widget.grab_focus(),
+            None => log::warn!("no widget with the id: {}", id),
+        }
     }
 
     fn create_app(mut self: &Rc<Self>)
@@ -102,8 +305,38 @@ where
             rc_win.set_default_size(800, 1000);
             rc_win.set_icon_name(Some("applications-graphics"));
             rc_win.set_title("Gtk backend");
+            if let Some(screen) = rc_win.get_screen() {
+                StyleContext::add_provider_for_screen(
+                    &screen,
+                    &self_clone.css_provider,
+                    gtk::STYLE_PROVIDER_PRIORITY_APPLICATION,
+                );
+            }
             self_clone.attach_root_widget(&rc_win);
+            let resize_clone = Rc::clone(&self_clone);
+            let last_size = Cell::new(None);
+            rc_win.connect_configure_event(move |_, event| {
+                let (width, height) = event.get_size();
+                let size = (width as i32, height as i32);
+                // the window is also configured when it is moved
+                if last_size.replace(Some(size)) != Some(size) {
+                    let messages = resize_clone
+                        .subscriptions
+                        .borrow()
+                        .resize_messages(size.0, size.1);
+                    for msg in messages {
+                        resize_clone.dispatch_inner(msg);
+                    }
+                }
+                false
+            });
             rc_win.show_all();
+            let focus = self_clone.focus.borrow().clone();
+            if let Some(id) = focus {
+                self_clone.grab_focus(&id);
+            }
+            let init_cmd = self_clone.app.borrow_mut().init();
+            self_clone.update_view(init_cmd);
         });
         self.application.run(&[]);
     }
@@ -120,20 +353,87 @@ where
         }
     }
 
-    fn from_node_tree<DSP>(program: &Rc<DSP>, widget_node: crate::Node<MSG>) -> GtkWidget
-    where
-        MSG: Debug + 'static,
-        DSP: Dispatch<MSG> + 'static,
-    {
+    /// the widgets are connected to the hover of their path of child indices in the view
+    fn from_node_tree(
+        program: &Rc<Self>,
+        widget_node: crate::Node<MSG>,
+        path: &mut Vec<usize>,
+    ) -> GtkWidget {
         match widget_node {
             crate::Node::Element(element) => {
                 let mut gtk_widget = Self::from_node(program, element.tag, &element.attrs);
+                gtk_widget.apply_sizing(&Sizing::from_attrs(&element.attrs));
+                let color = |key| find_value(key, &element.attrs).map(Color::from_value).flatten();
+                gtk_widget.apply_colors(
+                    color(AttribKey::Color),
+                    color(AttribKey::BackgroundColor),
+                );
+                let disabled = find_value(AttribKey::Disabled, &element.attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(false);
+                if let (true, Some(widget)) = (disabled, gtk_widget.as_widget()) {
+                    // an insensitive widget is greyed out and its children too
+                    widget.set_sensitive(false);
+                }
                 let mut children = vec![];
-                for child in element.children {
-                    let gtk_child = Self::from_node_tree(program, child);
+                for (index, child) in element.children.into_iter().enumerate() {
+                    path.push(index);
+                    let gtk_child = Self::from_node_tree(program, child, path);
+                    path.pop();
                     children.push(gtk_child);
                 }
                 gtk_widget.add_children(children);
+                if let Some(widget) = gtk_widget.as_widget() {
+                    if let Some(id) = find_value(AttribKey::Id, &element.attrs) {
+                        widget.set_widget_name(&id.to_string());
+                    }
+                    if let Some(classes) = find_value(AttribKey::Class, &element.attrs) {
+                        // the classes can also be selected in the css of the gtk theme
+                        let style_context = widget.get_style_context();
+                        for class in classes.to_string().split_whitespace() {
+                            style_context.add_class(class);
+                        }
+                    }
+                    let autofocus = find_value(AttribKey::Autofocus, &element.attrs)
+                        .map(|v| v.as_bool())
+                        .flatten()
+                        .unwrap_or(false);
+                    if autofocus {
+                        widget.connect_map(|widget| widget.grab_focus());
+                    }
+                    apply_accessibility(widget, &element.attrs);
+                    for att in element.attrs.iter() {
+                        if let (Some(name), Some(value)) =
+                            (att.name.custom_name("gtk"), att.get_value())
+                        {
+                            set_custom_property(widget, name, value);
+                        }
+                    }
+                    connect_key_events(widget, program, &element.attrs);
+                    connect_focus_events(widget, program, &element.attrs);
+                    connect_mouse_events(widget, program, &element.attrs);
+                    program.connect_hover(widget, path.clone());
+                }
+                let visible = find_value(AttribKey::Visible, &element.attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(true);
+                if let (false, Some(widget)) = (visible, gtk_widget.as_widget()) {
+                    // the children are shown beforehand, so they appear along with
+                    // the widget when it is made visible again, while the window
+                    // showing all of its children leaves this widget hidden
+                    widget.show_all();
+                    widget.hide();
+                    widget.set_no_show_all(true);
+                }
+                let alignment = find_value(AttribKey::Alignment, &element.attrs)
+                    .map(Alignment::from_value)
+                    .flatten();
+                let cross_alignment = find_value(AttribKey::CrossAlignment, &element.attrs)
+                    .map(Alignment::from_value)
+                    .flatten();
+                gtk_widget.apply_alignment(alignment, cross_alignment);
                 gtk_widget
             }
             crate::Node::Text(txt) => Button::new_with_label(&txt.text).into(),
@@ -151,17 +451,88 @@ where
                 vbox.into()
             }
             Widget::Hbox => gtk::Box::new(Orientation::Horizontal, 0).into(),
+            Widget::Frame => {
+                let title = find_value(AttribKey::Label, &attrs).map(|v| v.to_string());
+                let border_style = find_value(AttribKey::BorderStyle, &attrs)
+                    .map(BorderStyle::from_value)
+                    .flatten()
+                    .unwrap_or_default();
+
+                let frame = Frame::new(title.as_ref().map(|title| title.as_str()));
+                frame.set_shadow_type(shadow_type(border_style));
+                // a frame can only hold a single child, so the children
+                // are added into a vertical box inside the frame
+                let vbox = gtk::Box::new(Orientation::Vertical, 0);
+                frame.add(&vbox);
+                GtkWidget::Frame(frame, vbox)
+            }
+            Widget::Separator(orientation) => {
+                let orientation = match orientation {
+                    property::Orientation::Horizontal => Orientation::Horizontal,
+                    property::Orientation::Vertical => Orientation::Vertical,
+                };
+                GtkWidget::Separator(Separator::new(orientation))
+            }
+            Widget::Expander => {
+                let header = find_value(AttribKey::Label, &attrs).map(|v| v.to_string());
+                let expanded = find_value(AttribKey::Expanded, &attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(false);
+
+                let expander = Expander::new(header.as_ref().map(|header| header.as_str()));
+                expander.set_expanded(expanded);
+                if let Some(cb) = find_callback(AttribKey::ToggleEvent, &attrs) {
+                    let cb_clone = cb.clone();
+                    let program_clone = Rc::clone(&program);
+                    expander.connect_property_expanded_notify(move |expander| {
+                        let input_event = InputEvent::new(expander.get_expanded().to_string());
+                        let msg = cb_clone.emit(input_event);
+                        program_clone.dispatch(msg);
+                    });
+                }
+                // an expander can only hold a single child, so the children
+                // are added into a vertical box inside the expander
+                let vbox = gtk::Box::new(Orientation::Vertical, 0);
+                expander.add(&vbox);
+                GtkWidget::Expander(expander, vbox)
+            }
             Widget::Button => {
                 let label = find_value(AttribKey::Label, &attrs)
                     .map(|v| v.to_string())
                     .unwrap_or(String::new());
 
                 let btn = Button::new_with_label(&label);
+                if let Some(btn_icon) = find_value(AttribKey::Icon, &attrs)
+                    .map(Icon::from_value)
+                    .flatten()
+                {
+                    let image =
+                        Image::new_from_icon_name(Some(icon_name(btn_icon)), IconSize::Button);
+                    btn.set_image(Some(&image));
+                    btn.set_always_show_image(true);
+                }
                 if let Some(cb) = find_callback(AttribKey::ClickEvent, &attrs) {
+                    // the clicked signal has no pointer position, so it is taken from
+                    // the last press, a click from the keyboard has no position
+                    let last_press = Rc::new(RefCell::new(None));
+                    let last_press_clone = Rc::clone(&last_press);
+                    btn.connect_button_press_event(move |btn, event_button| {
+                        *last_press_clone.borrow_mut() = Some(pointer_event(
+                            "click",
+                            btn.upcast_ref(),
+                            event_button.get_position(),
+                            mouse_button(event_button.get_button()),
+                        ));
+                        Inhibit(false)
+                    });
                     let cb_clone = cb.clone();
                     let program_clone = Rc::clone(&program);
                     btn.connect_clicked(move |_| {
-                        let mouse_event = MouseEvent::default();
+                        let mouse_event = last_press.borrow_mut().take().unwrap_or(MouseEvent {
+                            r#type: "click",
+                            ..Default::default()
+                        });
                         let msg = cb_clone.emit(mouse_event);
                         program_clone.dispatch(msg);
                     });
@@ -176,6 +547,9 @@ where
 
                 let buffer = EntryBuffer::new(Some(&*value));
                 let entry = Entry::new_with_buffer(&buffer);
+                if let Some(placeholder) = find_value(AttribKey::Placeholder, &attrs) {
+                    entry.set_placeholder_text(Some(&placeholder.to_string()));
+                }
 
                 if let Some(cb) = find_callback(AttribKey::InputEvent, &attrs) {
                     let cb_clone = cb.clone();
@@ -201,6 +575,7 @@ where
 
                 let cb = CheckButton::new_with_label(&label);
                 cb.set_property("active", &value);
+                connect_toggled(cb.upcast_ref(), program, attrs);
                 GtkWidget::Checkbox(cb)
             }
             Widget::Radio => {
@@ -214,8 +589,21 @@ where
                     .unwrap_or(false);
                 let rb = RadioButton::new_with_label(&label);
                 rb.set_property("active", &value);
+                connect_toggled(rb.upcast_ref(), program, attrs);
                 GtkWidget::Radio(rb)
             }
+            Widget::Icon(icon, size) => {
+                let icon_size = match size {
+                    icon::IconSize::Small => IconSize::SmallToolbar,
+                    icon::IconSize::Medium => IconSize::LargeToolbar,
+                    icon::IconSize::Large => IconSize::Dialog,
+                };
+                let image = Image::new_from_icon_name(Some(icon_name(icon)), icon_size);
+                GtkWidget::Image(image)
+            }
+            Widget::LineChart(chart) => GtkWidget::Chart(chart_area(chart, false)),
+            Widget::BarChart(chart) => GtkWidget::Chart(chart_area(chart, true)),
+            Widget::Sparkline(series) => GtkWidget::Chart(sparkline_area(Chart::new(vec![series]))),
             Widget::Image(bytes) => {
                 let image = Image::new();
                 //TODO: also deal with other formats
@@ -260,6 +648,10 @@ where
 
 enum GtkWidget {
     GBox(gtk::Box),
+    Frame(Frame, gtk::Box),
+    Expander(Expander, gtk::Box),
+    Separator(Separator),
+    Chart(DrawingArea),
     Button(Button),
     Text(TextView),
     TextInput(Entry),
@@ -274,6 +666,14 @@ impl GtkWidget {
                 let container: &Container = gbox.upcast_ref();
                 Some(container)
             }
+            GtkWidget::Frame(_frame, vbox) => {
+                let container: &Container = vbox.upcast_ref();
+                Some(container)
+            }
+            GtkWidget::Expander(_expander, vbox) => {
+                let container: &Container = vbox.upcast_ref();
+                Some(container)
+            }
             _ => None,
         }
     }
@@ -288,6 +688,22 @@ impl GtkWidget {
                 let widget: &gtk::Widget = cbox.upcast_ref();
                 Some(widget)
             }
+            GtkWidget::Frame(frame, _vbox) => {
+                let widget: &gtk::Widget = frame.upcast_ref();
+                Some(widget)
+            }
+            GtkWidget::Separator(separator) => {
+                let widget: &gtk::Widget = separator.upcast_ref();
+                Some(widget)
+            }
+            GtkWidget::Expander(expander, _vbox) => {
+                let widget: &gtk::Widget = expander.upcast_ref();
+                Some(widget)
+            }
+            GtkWidget::Chart(drawing_area) => {
+                let widget: &gtk::Widget = drawing_area.upcast_ref();
+                Some(widget)
+            }
             GtkWidget::Text(text_view) => {
                 let widget: &gtk::Widget = text_view.upcast_ref();
                 Some(widget)
@@ -311,6 +727,127 @@ impl GtkWidget {
         }
     }
 
+    /// gtk has no percentage or maximum sizes, any percentage width or height
+    /// makes the widget expand into the available space instead,
+    /// and flex shrink has no equivalent so it is ignored
+    fn apply_sizing(&self, sizing: &Sizing) {
+        let widget = match self.as_widget() {
+            Some(widget) => widget,
+            None => return,
+        };
+        let pixels = |length: Option<Length>| match length {
+            Some(Length::Px(px)) => px as i32,
+            _ => -1,
+        };
+        let width = pixels(sizing.width.or(sizing.min_width));
+        let height = pixels(sizing.height.or(sizing.min_height));
+        if width >= 0 || height >= 0 {
+            widget.set_size_request(width, height);
+        }
+        if let Some(Length::Percent(_)) = sizing.width {
+            widget.set_hexpand(true);
+        }
+        if let Some(Length::Percent(_)) = sizing.height {
+            widget.set_vexpand(true);
+        }
+        if let Some(grow) = sizing.flex_grow {
+            widget.set_hexpand(grow > 0.0);
+            widget.set_vexpand(grow > 0.0);
+        }
+        if let Some(margin) = sizing.margin {
+            let margin = margin as i32;
+            widget.set_margin_top(margin);
+            widget.set_margin_bottom(margin);
+            widget.set_margin_start(margin);
+            widget.set_margin_end(margin);
+        }
+        if let Some(padding) = sizing.padding {
+            if let Some(container) = self.as_container() {
+                container.set_border_width(padding as u32);
+            }
+        }
+        if let Some(spacing) = sizing.spacing {
+            if let GtkWidget::GBox(gbox) = self {
+                gbox.set_spacing(spacing as i32);
+            }
+        }
+    }
+
+    fn apply_colors(&self, color: Option<Color>, background_color: Option<Color>) {
+        if let Some(widget) = self.as_widget() {
+            if color.is_some() {
+                set_color(widget, &AttribKey::Color, color);
+            }
+            if background_color.is_some() {
+                set_color(widget, &AttribKey::BackgroundColor, background_color);
+            }
+        }
+    }
+
+    /// a box aligns its children along its orientation by giving the free space
+    /// to the children at the ends or to spacers between them,
+    /// and aligns each of its children across it
+    fn apply_alignment(&self, alignment: Option<Alignment>, cross_alignment: Option<Alignment>) {
+        match self {
+            GtkWidget::GBox(gbox) => {
+                let orientation = gbox.get_orientation();
+                let children = gbox.get_children();
+                let expand = |child: &gtk::Widget, child_align: Align| {
+                    gbox.set_child_packing(child, true, true, 0, PackType::Start);
+                    match orientation {
+                        Orientation::Vertical => child.set_valign(child_align),
+                        _ => child.set_halign(child_align),
+                    }
+                };
+                match (alignment, children.first(), children.last()) {
+                    (Some(Alignment::End), Some(first), _) => expand(first, Align::End),
+                    (Some(Alignment::Center), Some(first), Some(last)) if first == last => {
+                        expand(first, Align::Center)
+                    }
+                    // the first and the last children share the free space equally
+                    (Some(Alignment::Center), Some(first), Some(last)) => {
+                        expand(first, Align::End);
+                        expand(last, Align::Start);
+                    }
+                    (Some(Alignment::Stretch), _, _) => {
+                        for child in children.iter() {
+                            expand(child, Align::Fill);
+                        }
+                    }
+                    (Some(Alignment::SpaceBetween), _, _) => {
+                        for position in (1..children.len()).rev() {
+                            let spacer = gtk::Box::new(orientation, 0);
+                            spacer.set_widget_name(SPACER_NAME);
+                            gbox.pack_start(&spacer, true, true, 0);
+                            gbox.reorder_child(&spacer, position as i32);
+                        }
+                    }
+                    _ => (),
+                }
+                if let Some(cross_alignment) = cross_alignment {
+                    for child in children {
+                        match orientation {
+                            Orientation::Vertical => child.set_halign(align(cross_alignment)),
+                            _ => child.set_valign(align(cross_alignment)),
+                        }
+                    }
+                }
+            }
+            GtkWidget::Text(text_view) => {
+                if let Some(alignment) = alignment {
+                    let justification = match alignment {
+                        Alignment::Start => Justification::Left,
+                        Alignment::Center => Justification::Center,
+                        Alignment::End => Justification::Right,
+                        Alignment::Stretch | Alignment::SpaceBetween => Justification::Fill,
+                    };
+                    text_view.set_justification(justification);
+                }
+            }
+            _ => (),
+        }
+    }
+
     fn add_children(&self, children: Vec<GtkWidget>) {
         if let Some(container) = self.as_container() {
             for child in children {
@@ -333,6 +870,608 @@ impl From<gtk::Box> for GtkWidget {
     }
 }
 
+/// space at the left and bottom of the chart for the axis labels
+const CHART_MARGIN: f64 = 30.0;
+
+/// a drawing area which draws the line or bar chart with cairo,
+/// scaled to the size allocated to it
+fn chart_area(chart: Chart, bars: bool) -> DrawingArea {
+    let drawing_area = DrawingArea::new();
+    drawing_area.set_size_request(400, 200);
+    drawing_area.connect_draw(move |drawing_area, cr| {
+        let width = drawing_area.get_allocated_width() as f64;
+        let height = drawing_area.get_allocated_height() as f64;
+        let plot_height = height - CHART_MARGIN;
+        let bounds = chart.bounds();
+        let point_count = std::cmp::max(chart.point_count(), 1);
+        let step = (width - CHART_MARGIN) / point_count as f64;
+        // the y coordinate of a value normalized to 0.0..=1.0
+        let y_of = |v: f64| plot_height * (1.0 - v);
+        let baseline = y_of(-bounds.0 / (bounds.1 - bounds.0).max(std::f64::EPSILON));
+
+        cr.set_source_rgb(0.0, 0.0, 0.0);
+        cr.set_line_width(1.0);
+        cr.move_to(CHART_MARGIN, 0.0);
+        cr.line_to(CHART_MARGIN, plot_height);
+        cr.move_to(CHART_MARGIN, baseline);
+        cr.line_to(width, baseline);
+        cr.stroke();
+
+        cr.set_font_size(10.0);
+        for (i, label) in chart.x_labels.iter().enumerate() {
+            let extents = cr.text_extents(label);
+            cr.move_to(
+                CHART_MARGIN + step * (i as f64 + 0.5) - extents.width / 2.0,
+                height - 8.0,
+            );
+            cr.show_text(label);
+        }
+        let y_step = plot_height / std::cmp::max(chart.y_labels.len() as i32 - 1, 1) as f64;
+        for (i, label) in chart.y_labels.iter().enumerate() {
+            let extents = cr.text_extents(label);
+            cr.move_to(
+                CHART_MARGIN - 4.0 - extents.width,
+                plot_height - y_step * i as f64,
+            );
+            cr.show_text(label);
+        }
+
+        let series_count = std::cmp::max(chart.series.len(), 1) as f64;
+        for (s, series) in chart.series.iter().enumerate() {
+            let (red, green, blue) = series.color.to_rgb_f64();
+            cr.set_source_rgb(red, green, blue);
+            let normalized = series.normalized(bounds);
+            if bars {
+                let bar_width = step * 0.8 / series_count;
+                for (i, v) in normalized.iter().enumerate() {
+                    let y = y_of(*v);
+                    cr.rectangle(
+                        CHART_MARGIN + step * (i as f64 + 0.1) + bar_width * s as f64,
+                        y.min(baseline),
+                        bar_width,
+                        (baseline - y).abs(),
+                    );
+                    cr.fill();
+                }
+            } else {
+                cr.set_line_width(2.0);
+                for (i, v) in normalized.iter().enumerate() {
+                    let x = CHART_MARGIN + step * (i as f64 + 0.5);
+                    if i == 0 {
+                        cr.move_to(x, y_of(*v));
+                    } else {
+                        cr.line_to(x, y_of(*v));
+                    }
+                }
+                cr.stroke();
+            }
+        }
+        Inhibit(false)
+    });
+    drawing_area
+}
+
+/// a drawing area which draws the single series
+/// of the chart as a small line without axes
+fn sparkline_area(chart: Chart) -> DrawingArea {
+    let drawing_area = DrawingArea::new();
+    drawing_area.set_size_request(100, 20);
+    drawing_area.connect_draw(move |drawing_area, cr| {
+        let width = drawing_area.get_allocated_width() as f64;
+        let height = drawing_area.get_allocated_height() as f64;
+        for series in chart.series.iter() {
+            let normalized = series.normalized(series.bounds());
+            let step = width / std::cmp::max(normalized.len() as i32 - 1, 1) as f64;
+            let (red, green, blue) = series.color.to_rgb_f64();
+            cr.set_source_rgb(red, green, blue);
+            cr.set_line_width(1.0);
+            for (i, v) in normalized.iter().enumerate() {
+                let (x, y) = (step * i as f64, height * (1.0 - v));
+                if i == 0 {
+                    cr.move_to(x, y);
+                } else {
+                    cr.line_to(x, y);
+                }
+            }
+            cr.stroke();
+        }
+        Inhibit(false)
+    });
+    drawing_area
+}
+
+/// the freedesktop theme icon name of the icon
+fn icon_name(icon: Icon) -> &'static str {
+    match icon {
+        Icon::Save => "document-save",
+        Icon::Open => "document-open",
+        Icon::Delete => "edit-delete",
+        Icon::Warning => "dialog-warning",
+        Icon::Error => "dialog-error",
+        Icon::Info => "dialog-information",
+        Icon::Add => "list-add",
+        Icon::Remove => "list-remove",
+        Icon::Edit => "accessories-text-editor",
+        Icon::Search => "edit-find",
+        Icon::Close => "window-close",
+        Icon::Refresh => "view-refresh",
+    }
+}
+
+/// replace the css of the provider with the css generated from the theme,
+/// the widgets it is added to are restyled right away
+/// the colors of a widget and the css provider they are loaded in
+struct WidgetColors {
+    css_provider: CssProvider,
+    color: Option<Color>,
+    background_color: Option<Color>,
+}
+
+thread_local! {
+    /// the colors of the widgets by their address, so a patch of one color keeps the other,
+    /// a widget is removed once it is destroyed
+    static WIDGET_COLORS: RefCell<HashMap<usize, WidgetColors>> = RefCell::new(HashMap::new());
+}
+
+/// set or unset a color of the widget with a css provider of the widget itself,
+/// which takes precedence over the theme of the application
+fn set_color(widget: &gtk::Widget, key: &AttribKey, value: Option<Color>) {
+    let address = widget.as_ptr() as usize;
+    WIDGET_COLORS.with(|widget_colors| {
+        let mut widget_colors = widget_colors.borrow_mut();
+        let colors = widget_colors.entry(address).or_insert_with(|| {
+            let css_provider = CssProvider::new();
+            widget
+                .get_style_context()
+                .add_provider(&css_provider, gtk::STYLE_PROVIDER_PRIORITY_USER);
+            widget.connect_destroy(move |_| {
+                WIDGET_COLORS.with(|widget_colors| widget_colors.borrow_mut().remove(&address));
+            });
+            WidgetColors {
+                css_provider,
+                color: None,
+                background_color: None,
+            }
+        });
+        match key {
+            AttribKey::Color => colors.color = value,
+            AttribKey::BackgroundColor => colors.background_color = value,
+            _ => return,
+        }
+        let mut css = String::from("* {");
+        if let Some(color) = &colors.color {
+            css += &format!(" color: {};", color.to_hex());
+        }
+        if let Some(background_color) = &colors.background_color {
+            css += &format!(
+                " background-image: none; background-color: {};",
+                background_color.to_hex()
+            );
+        }
+        css += " }";
+        if let Err(e) = colors.css_provider.load_from_data(css.as_bytes()) {
+            log::error!("unable to load the colors: {}", e);
+        }
+    });
+}
+
+fn load_theme(css_provider: &CssProvider, theme: Option<&Theme>) {
+    // without a theme the css is emptied, so the widgets keep the look of the gtk theme
+    let theme = match theme {
+        Some(theme) => theme,
+        None => {
+            if let Err(e) = css_provider.load_from_data(b"") {
+                log::error!("unable to unload the theme: {}", e);
+            }
+            return;
+        }
+    };
+    let palette = &theme.palette;
+    let css = format!(
+        r#"
+        * {{
+            font-family: {font_family};
+            font-size: {font_size}px;
+        }}
+        window, textview, textview text {{
+            background-color: {background};
+            color: {foreground};
+        }}
+        button {{
+            background-image: none;
+            background-color: {primary};
+            color: {on_primary};
+            border: 1px solid {border};
+            border-radius: {border_radius}px;
+            padding: {spacing}px;
+        }}
+        entry {{
+            background-color: {background};
+            color: {foreground};
+            border: 1px solid {border};
+            border-radius: {border_radius}px;
+        }}
+        frame > border, separator {{
+            border-color: {border};
+            background-color: {border};
+        }}
+        "#,
+        font_family = theme.font_family,
+        font_size = theme.font_size,
+        background = palette.background.to_hex(),
+        foreground = palette.foreground.to_hex(),
+        primary = palette.primary.to_hex(),
+        on_primary = palette.on_primary.to_hex(),
+        border = palette.border.to_hex(),
+        border_radius = theme.border_radius,
+        spacing = theme.spacing(1),
+    );
+    if let Err(e) = css_provider.load_from_data(css.as_bytes()) {
+        log::error!("unable to load the theme: {}", e);
+    }
+}
+
+/// the key events propagate from the focused widget up to its parents,
+/// gtk does not tell whether a key is repeating, so a press of the same key
+/// without a release in between is taken as a repeat
+fn connect_key_events<MSG, DSP>(
+    widget: &gtk::Widget,
+    program: &Rc<DSP>,
+    attrs: &Vec<Attribute<MSG>>,
+)
+where
+    MSG: Debug + 'static,
+    DSP: Dispatch<MSG> + 'static,
+{
+    let pressed_key: Rc<Cell<Option<u32>>> = Rc::new(Cell::new(None));
+    if let Some(cb) = find_callback(AttribKey::KeyPressEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        let pressed_key = Rc::clone(&pressed_key);
+        widget.connect_key_press_event(move |_, event_key| {
+            let keyval = event_key.get_keyval();
+            let repeat = pressed_key.replace(Some(keyval)) == Some(keyval);
+            let key_event = to_key_event(event_key, repeat);
+            let msg = cb_clone.emit(key_event);
+            program_clone.dispatch(msg);
+            Inhibit(false)
+        });
+    }
+    // the release is listened to even without a callback, so a key pressed
+    // again after it is released is not taken as a repeat
+    let release_cb = find_callback(AttribKey::KeyReleaseEvent, attrs).cloned();
+    let program_clone = Rc::clone(&program);
+    widget.connect_key_release_event(move |_, event_key| {
+        pressed_key.set(None);
+        if let Some(cb) = &release_cb {
+            let key_event = to_key_event(event_key, false);
+            let msg = cb.emit(key_event);
+            program_clone.dispatch(msg);
+        }
+        Inhibit(false)
+    });
+}
+
+/// the accessible name, description and role of the widget as read by ATK,
+/// a button showing only an icon is named after the icon.
+/// ATK has no way to mark a live region, so the politeness is not applied
+fn apply_accessibility<MSG>(widget: &gtk::Widget, attrs: &Vec<Attribute<MSG>>)
+where
+    MSG: 'static,
+{
+    let accessible = match widget.get_accessible() {
+        Some(accessible) => accessible,
+        None => return,
+    };
+    let string_value = |key| find_value(key, attrs).map(|v| v.to_string());
+    let icon_only = string_value(AttribKey::Label).map_or(true, |label| label.is_empty());
+    let accessible_label = string_value(AttribKey::AccessibleLabel).or_else(|| {
+        find_value(AttribKey::Icon, attrs)
+            .map(Icon::from_value)
+            .flatten()
+            .filter(|_| icon_only)
+            .map(|icon| icon.as_str().to_string())
+    });
+    if let Some(accessible_label) = accessible_label {
+        accessible.set_name(&accessible_label);
+    }
+    if let Some(description) = string_value(AttribKey::AccessibleDescription) {
+        accessible.set_description(&description);
+    }
+    if let Some(role) = string_value(AttribKey::Role).and_then(|role| atk_role(&role)) {
+        accessible.set_role(role);
+    }
+}
+
+/// set a property of the widget by its name, from a custom attribute
+/// addressed to gtk, the value is either a bool or a string
+fn set_custom_property(widget: &gtk::Widget, name: &str, value: &sauron_vdom::Value) {
+    let result = match value.as_bool() {
+        Some(value) => widget.set_property(name, &value),
+        None => widget.set_property(name, &value.to_string()),
+    };
+    if let Err(e) = result {
+        log::warn!("unable to set the property {}: {}", name, e);
+    }
+}
+
+/// the ATK role closest to the aria role of this name
+fn atk_role(role: &str) -> Option<atk::Role> {
+    match role {
+        "alert" => Some(atk::Role::Alert),
+        "button" => Some(atk::Role::PushButton),
+        "checkbox" => Some(atk::Role::CheckBox),
+        "group" => Some(atk::Role::Panel),
+        "heading" => Some(atk::Role::Heading),
+        "img" => Some(atk::Role::Image),
+        "link" => Some(atk::Role::Link),
+        "radio" => Some(atk::Role::RadioButton),
+        "separator" => Some(atk::Role::Separator),
+        "status" => Some(atk::Role::Statusbar),
+        "textbox" => Some(atk::Role::Entry),
+        _ => None,
+    }
+}
+
+fn connect_focus_events<MSG, DSP>(
+    widget: &gtk::Widget,
+    program: &Rc<DSP>,
+    attrs: &Vec<Attribute<MSG>>,
+) where
+    MSG: Debug + 'static,
+    DSP: Dispatch<MSG> + 'static,
+{
+    if let Some(cb) = find_callback(AttribKey::FocusEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.connect_focus_in_event(move |widget, _| {
+            let msg = cb_clone.emit(InputEvent::new(widget_value(widget)));
+            program_clone.dispatch(msg);
+            Inhibit(false)
+        });
+    }
+    if let Some(cb) = find_callback(AttribKey::BlurEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.connect_focus_out_event(move |widget, _| {
+            let msg = cb_clone.emit(InputEvent::new(widget_value(widget)));
+            program_clone.dispatch(msg);
+            Inhibit(false)
+        });
+    }
+}
+
+/// the text of an entry, the other widgets have no value
+fn widget_value(widget: &gtk::Widget) -> String {
+    widget
+        .downcast_ref::<Entry>()
+        .map(|entry| entry.get_buffer().get_text())
+        .unwrap_or_default()
+}
+
+fn find_widget_by_name(container: &Container, name: &str) -> Option<gtk::Widget> {
+    for child in container.get_children() {
+        if child.get_widget_name().map(|n| n.as_str() == name) == Some(true) {
+            return Some(child);
+        }
+        if let Some(child_container) = child.downcast_ref::<Container>() {
+            if let Some(found) = find_widget_by_name(child_container, name) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// emits the toggle event with the new state of a checkbox or a radio
+fn connect_toggled<MSG, DSP>(
+    toggle_button: &ToggleButton,
+    program: &Rc<DSP>,
+    attrs: &Vec<Attribute<MSG>>,
+) where
+    MSG: Debug + 'static,
+    DSP: Dispatch<MSG> + 'static,
+{
+    if let Some(cb) = find_callback(AttribKey::ToggleEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        toggle_button.connect_toggled(move |toggle_button| {
+            let input_event = InputEvent::new(toggle_button.get_active().to_string());
+            let msg = cb_clone.emit(input_event);
+            program_clone.dispatch(msg);
+        });
+    }
+}
+
+/// the pointer events propagate from the widget under the pointer up to its parents,
+/// except the enter and leave events
+fn connect_mouse_events<MSG, DSP>(
+    widget: &gtk::Widget,
+    program: &Rc<DSP>,
+    attrs: &Vec<Attribute<MSG>>,
+) where
+    MSG: Debug + 'static,
+    DSP: Dispatch<MSG> + 'static,
+{
+    if let Some(cb) = find_callback(AttribKey::DoubleClickEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.add_events(EventMask::BUTTON_PRESS_MASK);
+        widget.connect_button_press_event(move |widget, event_button| {
+            if event_button.get_event_type() == EventType::DoubleButtonPress {
+                let mouse_event = pointer_event(
+                    "dblclick",
+                    widget,
+                    event_button.get_position(),
+                    mouse_button(event_button.get_button()),
+                );
+                program_clone.dispatch(cb_clone.emit(mouse_event));
+            }
+            Inhibit(false)
+        });
+    }
+    if let Some(cb) = find_callback(AttribKey::MouseEnterEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.add_events(EventMask::ENTER_NOTIFY_MASK);
+        widget.connect_enter_notify_event(move |widget, event_crossing| {
+            let position = event_crossing.get_position();
+            let mouse_event = pointer_event("mouseenter", widget, position, MouseButton::Left);
+            program_clone.dispatch(cb_clone.emit(mouse_event));
+            Inhibit(false)
+        });
+    }
+    if let Some(cb) = find_callback(AttribKey::MouseLeaveEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.add_events(EventMask::LEAVE_NOTIFY_MASK);
+        widget.connect_leave_notify_event(move |widget, event_crossing| {
+            let position = event_crossing.get_position();
+            let mouse_event = pointer_event("mouseleave", widget, position, MouseButton::Left);
+            program_clone.dispatch(cb_clone.emit(mouse_event));
+            Inhibit(false)
+        });
+    }
+    if let Some(cb) = find_callback(AttribKey::MouseMoveEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.add_events(EventMask::POINTER_MOTION_MASK);
+        widget.connect_motion_notify_event(move |widget, event_motion| {
+            let state = event_motion.get_state();
+            let buttons = if state.contains(ModifierType::BUTTON3_MASK) {
+                MouseButton::Right
+            } else if state.contains(ModifierType::BUTTON2_MASK) {
+                MouseButton::Middle
+            } else {
+                MouseButton::Left
+            };
+            let position = event_motion.get_position();
+            let mouse_event = pointer_event("mousemove", widget, position, buttons);
+            program_clone.dispatch(cb_clone.emit(mouse_event));
+            Inhibit(false)
+        });
+    }
+    if let Some(cb) = find_callback(AttribKey::WheelEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(&program);
+        widget.add_events(EventMask::SCROLL_MASK);
+        widget.connect_scroll_event(move |widget, event_scroll| {
+            let wheel = match event_scroll.get_direction() {
+                ScrollDirection::Up => Some(MouseButton::WheelUp),
+                ScrollDirection::Down => Some(MouseButton::WheelDown),
+                ScrollDirection::Smooth => {
+                    let (_dx, dy) = event_scroll.get_delta();
+                    if dy < 0.0 {
+                        Some(MouseButton::WheelUp)
+                    } else if dy > 0.0 {
+                        Some(MouseButton::WheelDown)
+                    } else {
+                        None
+                    }
+                }
+                // the horizontal scroll has no equivalent mouse button
+                _ => None,
+            };
+            if let Some(wheel) = wheel {
+                let position = event_scroll.get_position();
+                let mouse_event = pointer_event("wheel", widget, position, wheel);
+                program_clone.dispatch(cb_clone.emit(mouse_event));
+            }
+            Inhibit(false)
+        });
+    }
+}
+
+/// the position of the pointer is relative to the window of the event,
+/// the widgets without a window of their own are placed in the window of their parent
+fn pointer_event(
+    event_type: &'static str,
+    widget: &gtk::Widget,
+    (x, y): (f64, f64),
+    buttons: MouseButton,
+) -> MouseEvent {
+    let (x, y) = if widget.get_has_window() {
+        (x as i32, y as i32)
+    } else {
+        let allocation = widget.get_allocation();
+        (x as i32 - allocation.x, y as i32 - allocation.y)
+    };
+    MouseEvent {
+        r#type: event_type,
+        coordinate: Coordinate::new(x, y),
+        buttons,
+        ..Default::default()
+    }
+}
+
+fn mouse_button(button: u32) -> MouseButton {
+    match button {
+        2 => MouseButton::Middle,
+        3 => MouseButton::Right,
+        _ => MouseButton::Left,
+    }
+}
+
+/// the keys are named the same as the key of the html keyboard event
+fn to_key_event(event_key: &gdk::EventKey, repeat: bool) -> KeyEvent {
+    let keyval = event_key.get_keyval();
+    let name = gdk::keyval_name(keyval).map(|name| name.to_string());
+    let key = match name.as_ref().map(|name| name.as_str()) {
+        Some("Return") | Some("KP_Enter") => "Enter".to_string(),
+        Some("BackSpace") => "Backspace".to_string(),
+        Some("Tab") | Some("ISO_Left_Tab") => "Tab".to_string(),
+        Some("Up") => "ArrowUp".to_string(),
+        Some("Down") => "ArrowDown".to_string(),
+        Some("Left") => "ArrowLeft".to_string(),
+        Some("Right") => "ArrowRight".to_string(),
+        Some("Page_Up") => "PageUp".to_string(),
+        Some("Page_Down") => "PageDown".to_string(),
+        Some("Shift_L") | Some("Shift_R") => "Shift".to_string(),
+        Some("Control_L") | Some("Control_R") => "Control".to_string(),
+        Some("Alt_L") | Some("Alt_R") => "Alt".to_string(),
+        _ => match gdk::keyval_to_unicode(keyval) {
+            Some(ch) if !ch.is_control() => ch.to_string(),
+            // the other keys such as Home, End, Delete, Escape and F1
+            // are named the same in gtk
+            _ => name.unwrap_or_else(|| "Unidentified".to_string()),
+        },
+    };
+    let state = event_key.get_state();
+    KeyEvent {
+        key,
+        modifier: Modifier {
+            alt_key: state.contains(ModifierType::MOD1_MASK),
+            ctrl_key: state.contains(ModifierType::CONTROL_MASK),
+            shift_key: state.contains(ModifierType::SHIFT_MASK),
+            meta_key: state.contains(ModifierType::META_MASK),
+        },
+        repeat,
+        ..Default::default()
+    }
+}
+
+fn is_spacer(widget: &gtk::Widget) -> bool {
+    widget.get_widget_name().map(|name| name.as_str() == SPACER_NAME) == Some(true)
+}
+
+fn align(alignment: Alignment) -> Align {
+    match alignment {
+        Alignment::Start => Align::Start,
+        Alignment::Center => Align::Center,
+        Alignment::End => Align::End,
+        Alignment::Stretch | Alignment::SpaceBetween => Align::Fill,
+    }
+}
+
+fn shadow_type(border_style: BorderStyle) -> ShadowType {
+    match border_style {
+        BorderStyle::None => ShadowType::None,
+        BorderStyle::Solid => ShadowType::In,
+        BorderStyle::Dashed => ShadowType::EtchedOut,
+        BorderStyle::Double => ShadowType::EtchedIn,
+    }
+}
+
 fn textview(txt: &str) -> GtkWidget {
     let buffer = TextBuffer::new(None::<&TextTagTable>);
     let text_view = TextView::new_with_buffer(&buffer);
@@ -1,24 +1,39 @@
-use crate::{AttribKey, Patch};
-use gtk::{prelude::*, Button, Container, ContainerExt, Widget};
+use crate::{
+    widget::{attribute::find_value, property::Color},
+    AttribKey, Node, Patch,
+};
+use atk::prelude::*;
+use gtk::{prelude::*, Button, Container, ContainerExt, Entry, Expander, Frame, Widget};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     rc::Rc,
 };
 
-pub fn apply_patches<MSG>(container: &Container, patches: &Vec<Patch<MSG>>)
+/// the patches are the diff from the old view, which the widgets were made from
+pub fn apply_patches<MSG>(container: &Container, old_vdom: &Node<MSG>, patches: &Vec<Patch<MSG>>)
 where
-    MSG: Debug,
+    MSG: Debug + 'static,
 {
-    let nodes_to_patch = find_nodes(container, patches);
-    println!("nodes to patch: {:#?}", nodes_to_patch);
+    let nodes_to_patch = find_nodes(container, old_vdom, patches);
+    println!("nodes to patch: {:#?}", nodes_to_patch.keys());
 
     for patch in patches {
         let patch_node_idx = patch.node_idx();
         println!("patching for {}", patch_node_idx);
-        let widget = nodes_to_patch
+        let (widget, old_node) = nodes_to_patch
             .get(&patch_node_idx)
             .expect("must have a node to patch");
+        // the classes the widget was given from the old view
+        let old_classes: Vec<String> = match old_node {
+            Node::Element(element) => find_value(AttribKey::Class, &element.attrs)
+                .map(|classes| classes.to_string())
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(ToString::to_string)
+                .collect(),
+            Node::Text(_) => vec![],
+        };
         println!("patching this widget: {:?}", widget);
         match patch {
             Patch::AddAttributes(_node_idx, attrs) => {
@@ -35,12 +50,75 @@ where
                             }
                         }
                     }
+                    if att.name == AttribKey::Disabled {
+                        let disabled = att.get_value().map(|v| v.as_bool()).flatten();
+                        widget.set_sensitive(!disabled.unwrap_or(false));
+                    }
+                    if att.name == AttribKey::Visible {
+                        let visible = att.get_value().map(|v| v.as_bool()).flatten();
+                        widget.set_visible(visible.unwrap_or(true));
+                    }
+                    if let (Some(name), Some(value)) =
+                        (att.name.custom_name("gtk"), att.get_value())
+                    {
+                        super::set_custom_property(widget, name, value);
+                    }
+                    if att.name == AttribKey::Color || att.name == AttribKey::BackgroundColor {
+                        let color = att.get_value().map(Color::from_value).flatten();
+                        super::set_color(widget, &att.name, color);
+                    }
+                    if let (AttribKey::Class, Some(value)) = (&att.name, att.get_value()) {
+                        let style_context = widget.get_style_context();
+                        for class in old_classes.iter() {
+                            style_context.remove_class(class);
+                        }
+                        for class in value.to_string().split_whitespace() {
+                            style_context.add_class(class);
+                        }
+                    }
+                    if let (AttribKey::Id, Some(value)) = (&att.name, att.get_value()) {
+                        widget.set_widget_name(&value.to_string());
+                    }
+                    if let (AttribKey::Placeholder, Some(entry), Some(value)) =
+                        (&att.name, widget.downcast_ref::<Entry>(), att.get_value())
+                    {
+                        entry.set_placeholder_text(Some(&value.to_string()));
+                    }
+                    if let (AttribKey::AccessibleLabel, Some(accessible), Some(value)) =
+                        (&att.name, widget.get_accessible(), att.get_value())
+                    {
+                        accessible.set_name(&value.to_string());
+                    }
+                    if let (AttribKey::AccessibleDescription, Some(accessible), Some(value)) =
+                        (&att.name, widget.get_accessible(), att.get_value())
+                    {
+                        accessible.set_description(&value.to_string());
+                    }
+                }
+            }
+            Patch::RemoveAttributes(_node_idx, attrs) => {
+                if attrs.iter().any(|att| **att == AttribKey::Disabled) {
+                    widget.set_sensitive(true);
+                }
+                if attrs.iter().any(|att| **att == AttribKey::Visible) {
+                    widget.set_visible(true);
+                }
+                if attrs.iter().any(|att| **att == AttribKey::Class) {
+                    let style_context = widget.get_style_context();
+                    for class in old_classes.iter() {
+                        style_context.remove_class(class);
+                    }
+                }
+                for key in &[AttribKey::Color, AttribKey::BackgroundColor] {
+                    if attrs.iter().any(|att| *att == key) {
+                        super::set_color(widget, key, None);
+                    }
                 }
             }
             Patch::AppendChildren(_node_idx, nodes) => {
                 println!("appending children..{}", nodes.len());
                 println!("widget is a: {:?}", widget);
-                if let Some(container) = widget.downcast_ref::<Container>() {
+                if let Some(container) = children_container(widget) {
                     println!("container is a: {:?}", container);
                     for node in nodes {
                         let btn = Button::new_with_label("btn here..");
@@ -53,8 +131,12 @@ where
             }
             Patch::TruncateChildren(_node_idx, num_children_remaining) => {
                 println!("Truncating children {}", num_children_remaining);
-                if let Some(container) = widget.downcast_ref::<Container>() {
-                    let children = container.get_children();
+                if let Some(container) = children_container(widget) {
+                    let children: Vec<Widget> = container
+                        .get_children()
+                        .into_iter()
+                        .filter(|child| !super::is_spacer(child))
+                        .collect();
                     for i in *num_children_remaining..children.len() {
                         container.remove(&children[i]);
                     }
@@ -65,7 +147,13 @@ where
     }
 }
 
-fn find_nodes<MSG>(root_node: &Container, patches: &[Patch<MSG>]) -> HashMap<usize, Widget> {
+/// the widgets of the nodes to patch, found by walking the widgets along with
+/// the view they were made from, so the nodes are numbered in the same order as the diff
+fn find_nodes<'a, MSG>(
+    root_node: &Container,
+    old_vdom: &'a Node<MSG>,
+    patches: &[Patch<MSG>],
+) -> HashMap<usize, (Widget, &'a Node<MSG>)> {
     let mut nodes_to_find = HashSet::new();
     let mut cur_node_idx = 0;
 
@@ -73,37 +161,66 @@ fn find_nodes<MSG>(root_node: &Container, patches: &[Patch<MSG>]) -> HashMap<usi
         nodes_to_find.insert(patch.node_idx());
     }
     println!("nodes to find: {:#?}", nodes_to_find);
-    find_nodes_recursive(root_node, &mut cur_node_idx, &nodes_to_find)
+    let root_widget: Widget = root_node.clone().upcast();
+    find_nodes_recursive(&root_widget, old_vdom, &mut cur_node_idx, &nodes_to_find)
 }
 
-fn find_nodes_recursive(
-    root_node: &Container,
+fn find_nodes_recursive<'a, MSG>(
+    widget: &Widget,
+    node: &'a Node<MSG>,
     cur_node_idx: &mut usize,
     nodes_to_find: &HashSet<usize>,
-) -> HashMap<usize, Widget> {
-    let mut nodes_to_patch: HashMap<usize, Widget> = HashMap::new();
+) -> HashMap<usize, (Widget, &'a Node<MSG>)> {
+    let mut nodes_to_patch: HashMap<usize, (Widget, &'a Node<MSG>)> = HashMap::new();
     println!("cur_node_idx: {}", cur_node_idx);
 
-    if nodes_to_find.get(&cur_node_idx).is_some() {
-        let root_widget: Widget = root_node.clone().upcast();
+    if nodes_to_find.contains(cur_node_idx) {
         println!(" --- >> found here: {}", cur_node_idx);
-        if let Some(btn) = root_widget.downcast_ref::<Button>() {
-            println!("with a label of: {:?}", btn.get_label());
-        }
-        nodes_to_patch.insert(*cur_node_idx, root_widget);
+        nodes_to_patch.insert(*cur_node_idx, (widget.clone(), node));
     }
-
-    let children = root_node.get_children();
-    let child_node_count = children.len();
-
     *cur_node_idx += 1;
 
-    for i in 0..child_node_count {
-        let child_node = children[i].clone();
-        if let Some(container) = child_node.downcast_ref::<Container>() {
-            let child_nodes_to_patch = find_nodes_recursive(container, cur_node_idx, nodes_to_find);
-            nodes_to_patch.extend(child_nodes_to_patch);
+    let element = match node {
+        Node::Element(element) if !element.children.is_empty() => element,
+        _ => return nodes_to_patch,
+    };
+    let children: Vec<Widget> = children_container(widget)
+        .map(|container| container.get_children())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|child| !super::is_spacer(child))
+        .collect();
+    for (index, child_node) in element.children.iter().enumerate() {
+        match children.get(index) {
+            Some(child_widget) => {
+                let child_nodes_to_patch =
+                    find_nodes_recursive(child_widget, child_node, cur_node_idx, nodes_to_find);
+                nodes_to_patch.extend(child_nodes_to_patch);
+            }
+            // the nodes without a widget are still counted
+            None => *cur_node_idx += count_nodes(child_node),
         }
     }
     nodes_to_patch
 }
+
+/// the number of nodes in this tree, including itself
+fn count_nodes<MSG>(node: &Node<MSG>) -> usize {
+    match node {
+        Node::Element(element) => 1 + element.children.iter().map(count_nodes).sum::<usize>(),
+        Node::Text(_) => 1,
+    }
+}
+
+/// the children of a frame or an expander are held in an inner box,
+/// the other containers hold their children themselves
+fn children_container(widget: &Widget) -> Option<Container> {
+    let inner_child = if let Some(frame) = widget.downcast_ref::<Frame>() {
+        frame.get_child()
+    } else if let Some(expander) = widget.downcast_ref::<Expander>() {
+        expander.get_child()
+    } else {
+        return widget.clone().downcast::<Container>().ok();
+    };
+    inner_child.and_then(|child| child.downcast::<Container>().ok())
+}
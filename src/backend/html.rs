@@ -1,13 +1,41 @@
-use crate::{widget::attribute::find_value, AttribKey, Attribute, Backend, Component, Widget};
+use crate::{
+    cmd::Command,
+    sub::Source,
+    widget::{
+        attribute::find_value,
+        chart::{Chart, Series},
+        icon::{Icon, IconSize},
+        property::{Alignment, BorderStyle, Color, Orientation, Politeness},
+        sizing::Sizing,
+    },
+    AttribKey, Attribute, Backend, Cmd, Component, Sub, Theme, Widget,
+};
+use super::mark_state;
 use image::ImageFormat;
 use sauron::{
-    html::{attributes::*, div, events::mapper, img, input, text},
+    html::{
+        attributes::*,
+        button, details, div,
+        events::{mapper, on},
+        fieldset, hr, img, input, legend, summary, text,
+    },
     prelude::*,
+    svg::{line, path, polyline, rect, svg, text as svg_text},
     Component as SauronComponent, DomUpdater, Program,
 };
-use sauron_vdom::Callback;
-use std::{cell::RefCell, fmt::Debug, marker::PhantomData, rc::Rc};
-use wasm_bindgen::JsCast;
+use sauron_vdom::{
+    event::{Coordinate, InputEvent, MouseButton, MouseEvent},
+    Callback, Dispatch,
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    marker::PhantomData,
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{HtmlDetailsElement, HtmlElement, HtmlInputElement};
 
 pub struct HtmlApp<APP, MSG>
 where
@@ -15,9 +43,28 @@ where
     APP: Component<MSG> + 'static,
 {
     app: APP,
+    /// the id of the element which was last requested to have the focus
+    focus: Option<String>,
+    /// the subscriptions of the app, read by the running sources when they fire
+    subscriptions: Rc<RefCell<Sub<MSG>>>,
+    sources: Rc<RefCell<HashMap<Source, RunningSource>>>,
+    /// the path of child indices to the widget under the pointer
+    hovered: Rc<RefCell<Option<Vec<usize>>>>,
     _phantom_data: PhantomData<MSG>,
 }
 
+/// a timer or listener started for the subscriptions, it is stopped when dropped.
+/// The closures are owned by javascript, since a source can be stopped
+/// by the update of a message it dispatched
+enum RunningSource {
+    Interval(i32),
+    /// the frames are requested again for as long as this is set
+    AnimationFrame(Rc<Cell<bool>>),
+    Resize(JsValue),
+}
+
+type HtmlCmd<APP, MSG> = sauron_vdom::Cmd<Program<HtmlApp<APP, MSG>, MSG>, MSG>;
+
 pub struct HtmlBackend<APP, MSG>
 where
     MSG: Clone + Debug + 'static,
@@ -32,11 +79,40 @@ where
     APP: Component<MSG> + 'static,
 {
     fn new(app: APP) -> Self {
+        let focus = app.focus();
+        if let Some(id) = &focus {
+            focus_element_later(id.to_string());
+        }
+        let subscriptions = app.subscriptions();
         HtmlApp {
             app,
+            focus,
+            subscriptions: Rc::new(RefCell::new(subscriptions)),
+            sources: Rc::new(RefCell::new(HashMap::new())),
+            hovered: Rc::new(RefCell::new(None)),
             _phantom_data: PhantomData,
         }
     }
+
+    /// start the sources of the new subscriptions and stop those no longer subscribed to,
+    /// they are started in a command since they dispatch to the program
+    fn sync_subscriptions(&self) -> HtmlCmd<APP, MSG> {
+        let subscriptions = Rc::clone(&self.subscriptions);
+        let sources = Rc::clone(&self.sources);
+        sauron_vdom::Cmd::new(move |program: Rc<Program<HtmlApp<APP, MSG>, MSG>>| {
+            let subscribed = subscriptions.borrow().sources();
+            let mut sources = sources.borrow_mut();
+            sources.retain(|source, _| subscribed.contains(source));
+            for source in subscribed {
+                if !sources.contains_key(&source) {
+                    let running = start_source(source, &program, &subscriptions);
+                    if let Some(running) = running {
+                        sources.insert(source, running);
+                    }
+                }
+            }
+        })
+    }
 }
 
 impl<APP, MSG> sauron::Component<MSG> for HtmlApp<APP, MSG>
@@ -45,14 +121,32 @@ where
     APP: Component<MSG> + 'static,
 {
     fn update(&mut self, msg: MSG) -> sauron_vdom::Cmd<sauron::Program<Self, MSG>, MSG> {
-        self.app.update(msg);
-        sauron_vdom::Cmd::none()
+        let cmd = self.app.update(msg);
+        *self.subscriptions.borrow_mut() = self.app.subscriptions();
+        let focus = self.app.focus();
+        if focus != self.focus {
+            if let Some(id) = &focus {
+                focus_element_later(id.to_string());
+            }
+            self.focus = focus;
+        }
+        sauron_vdom::Cmd::batch(vec![to_program_cmd(cmd), self.sync_subscriptions()])
     }
 
     fn view(&self) -> sauron::Node<MSG> {
-        let view = self.app.view();
-        let html_view = widget_tree_to_html_node(view);
-        html_view
+        let mut view = self.app.view();
+        if let Some(path) = &*self.hovered.borrow() {
+            mark_state(&mut view, path, AttribKey::Hovered);
+        }
+        let view = self.app.style_sheet().apply(view);
+        // the theme is set as css variables in a container of the view
+        match self.app.theme() {
+            Some(theme) => div(
+                vec![styles(theme_styles(&theme))],
+                vec![widget_tree_to_html_node(view, true)],
+            ),
+            None => widget_tree_to_html_node(view, false),
+        }
     }
 }
 
@@ -61,38 +155,394 @@ where
     MSG: Clone + Debug + 'static,
     APP: Component<MSG> + 'static,
 {
-    fn init(app: APP) -> Rc<Self> {
+    fn init(mut app: APP) -> Rc<Self> {
         console_log::init_with_level(log::Level::Trace);
         log::trace!("Html app started..");
+        // the program owns the app once it is mounted, so the app is initialized before
+        // and the view mounted includes the state of its init
+        let init_cmd = app.init();
         let html_app = HtmlApp::new(app);
+        let hovered = Rc::clone(&html_app.hovered);
+        let start = sauron_vdom::Cmd::batch(vec![
+            to_program_cmd(init_cmd),
+            html_app.sync_subscriptions(),
+        ]);
         let program = sauron::Program::mount_to_body(html_app);
+        start.emit(&program);
+        listen_hover(&program, hovered);
         let backend = HtmlBackend { program };
         Rc::new(backend)
     }
 }
 
+/// the data attribute holding the path of child indices of the widget an element is made of
+const PATH_ATTRIBUTE: &str = "data-sauron-native-path";
+
+/// track the widget under the pointer and update the view when it changes,
+/// the pointer events of the elements bubble up to the document
+fn listen_hover<APP, MSG>(
+    program: &Rc<Program<HtmlApp<APP, MSG>, MSG>>,
+    hovered: Rc<RefCell<Option<Vec<usize>>>>,
+) where
+    MSG: Clone + Debug + 'static,
+    APP: Component<MSG> + 'static,
+{
+    let document = match web_sys::window().and_then(|window| window.document()) {
+        Some(document) => document,
+        None => return,
+    };
+    let program = Rc::clone(program);
+    let set_hovered = Rc::new(move |path: Option<Vec<usize>>| {
+        if *hovered.borrow() != path {
+            *hovered.borrow_mut() = path;
+            program.update_dom();
+        }
+    });
+    let on_over = {
+        let set_hovered = Rc::clone(&set_hovered);
+        Closure::wrap(Box::new(move |event: web_sys::Event| {
+            let path = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|element| {
+                    element.closest(&format!("[{}]", PATH_ATTRIBUTE)).ok().flatten()
+                })
+                .and_then(|element| element.get_attribute(PATH_ATTRIBUTE))
+                .map(|path| parse_path(&path));
+            set_hovered(path);
+        }) as Box<dyn FnMut(web_sys::Event)>)
+    };
+    // the pointer left the page when there is no element it moved to
+    let on_out = Closure::wrap(Box::new(move |event: web_sys::Event| {
+        let left_page = event
+            .dyn_ref::<web_sys::MouseEvent>()
+            .map(|mouse_event| mouse_event.related_target().is_none())
+            .unwrap_or(false);
+        if left_page {
+            set_hovered(None);
+        }
+    }) as Box<dyn FnMut(web_sys::Event)>);
+    document
+        .add_event_listener_with_callback("mouseover", on_over.as_ref().unchecked_ref())
+        .expect("must add the mouseover listener");
+    document
+        .add_event_listener_with_callback("mouseout", on_out.as_ref().unchecked_ref())
+        .expect("must add the mouseout listener");
+    on_over.forget();
+    on_out.forget();
+}
+
+/// the child indices of a path written as `0.2.1`, the root widget has an empty path
+fn parse_path(path: &str) -> Vec<usize> {
+    path.split('.').filter_map(|index| index.parse().ok()).collect()
+}
+
+/// convert the commands of the app into the commands the program executes
+/// once the view is patched
+fn to_program_cmd<APP, MSG>(cmd: Cmd<MSG>) -> HtmlCmd<APP, MSG>
+where
+    MSG: Clone + Debug + 'static,
+    APP: Component<MSG> + 'static,
+{
+    let cmds = cmd
+        .into_commands()
+        .into_iter()
+        .map(|command| {
+            // the program command can be called more than once, the effect only runs once
+            let command = RefCell::new(Some(command));
+            sauron_vdom::Cmd::new(move |program: Rc<Program<HtmlApp<APP, MSG>, MSG>>| {
+                match command.borrow_mut().take() {
+                    Some(Command::Effect(effect)) => {
+                        if let Some(msg) = effect() {
+                            program.dispatch(msg);
+                        }
+                    }
+                    Some(Command::Perform(future)) => {
+                        let program = Rc::clone(&program);
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let msg = future.await;
+                            program.dispatch(msg);
+                        });
+                    }
+                    Some(Command::Focus(id)) => focus_element_later(id),
+                    Some(Command::ScrollTo(id)) => {
+                        let element = web_sys::window()
+                            .and_then(|window| window.document())
+                            .and_then(|document| document.get_element_by_id(&id));
+                        if let Some(element) = element {
+                            element.scroll_into_view();
+                        }
+                    }
+                    Some(Command::Quit) => {
+                        log::warn!("the browser tab can not be closed by the app")
+                    }
+                    None => (),
+                }
+            })
+        })
+        .collect();
+    sauron_vdom::Cmd::batch(cmds)
+}
+
+/// start the timer or listener of the subscriptions to this source
+fn start_source<APP, MSG>(
+    source: Source,
+    program: &Rc<Program<HtmlApp<APP, MSG>, MSG>>,
+    subscriptions: &Rc<RefCell<Sub<MSG>>>,
+) -> Option<RunningSource>
+where
+    MSG: Clone + Debug + 'static,
+    APP: Component<MSG> + 'static,
+{
+    let window = web_sys::window()?;
+    let program = Rc::clone(program);
+    let subscriptions = Rc::clone(subscriptions);
+    // the messages are collected first, since the update replaces the subscriptions
+    let dispatch_all = move |messages: Vec<MSG>| {
+        for msg in messages {
+            program.dispatch(msg);
+        }
+    };
+    match source {
+        Source::Every(interval) => {
+            let tick = Closure::wrap(Box::new(move || {
+                let messages = subscriptions.borrow().every_messages(interval);
+                dispatch_all(messages);
+            }) as Box<dyn FnMut()>);
+            let tick = tick.into_js_value();
+            let handle = window
+                .set_interval_with_callback_and_timeout_and_arguments_0(
+                    tick.unchecked_ref(),
+                    interval.as_millis() as i32,
+                )
+                .ok()?;
+            Some(RunningSource::Interval(handle))
+        }
+        Source::AnimationFrame => {
+            let running = Rc::new(Cell::new(true));
+            let frame: Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>> = Rc::new(RefCell::new(None));
+            let next_frame = Rc::clone(&frame);
+            let is_running = Rc::clone(&running);
+            let mut last_timestamp: Option<f64> = None;
+            *frame.borrow_mut() = Some(Closure::wrap(Box::new(move |timestamp: f64| {
+                if !is_running.get() {
+                    // drop the closure, which holds the last reference to itself
+                    let _ = next_frame.borrow_mut().take();
+                    return;
+                }
+                let elapsed = last_timestamp.map(|last| timestamp - last).unwrap_or(0.0);
+                last_timestamp = Some(timestamp);
+                let messages = subscriptions.borrow().animation_frame_messages(elapsed);
+                dispatch_all(messages);
+                if let (Some(window), Some(next_frame)) =
+                    (web_sys::window(), next_frame.borrow().as_ref())
+                {
+                    let _ = window.request_animation_frame(next_frame.as_ref().unchecked_ref());
+                }
+            }) as Box<dyn FnMut(f64)>));
+            if let Some(frame) = frame.borrow().as_ref() {
+                window
+                    .request_animation_frame(frame.as_ref().unchecked_ref())
+                    .ok()?;
+            }
+            Some(RunningSource::AnimationFrame(running))
+        }
+        Source::Resize => {
+            let resize = Closure::wrap(Box::new(move || {
+                let size = web_sys::window().map(|window| {
+                    let length = |value: Result<JsValue, JsValue>| {
+                        value.ok().and_then(|v| v.as_f64()).unwrap_or(0.0) as i32
+                    };
+                    (length(window.inner_width()), length(window.inner_height()))
+                });
+                if let Some((width, height)) = size {
+                    let messages = subscriptions.borrow().resize_messages(width, height);
+                    dispatch_all(messages);
+                }
+            }) as Box<dyn FnMut()>);
+            let resize = resize.into_js_value();
+            window
+                .add_event_listener_with_callback("resize", resize.unchecked_ref())
+                .ok()?;
+            Some(RunningSource::Resize(resize))
+        }
+    }
+}
+
+impl Drop for RunningSource {
+    fn drop(&mut self) {
+        match self {
+            RunningSource::Interval(handle) => {
+                if let Some(window) = web_sys::window() {
+                    window.clear_interval_with_handle(*handle);
+                }
+            }
+            RunningSource::AnimationFrame(running) => running.set(false),
+            RunningSource::Resize(resize) => {
+                if let Some(window) = web_sys::window() {
+                    let _ = window
+                        .remove_event_listener_with_callback("resize", resize.unchecked_ref());
+                }
+            }
+        }
+    }
+}
+
+/// focus the element once the view is patched, so a new element can take the focus
+fn focus_element_later(id: String) {
+    let focus = Closure::once_into_js(move || {
+        let element = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&id))
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        if let Some(element) = element {
+            let _ = element.focus();
+        }
+    });
+    if let Some(window) = web_sys::window() {
+        let _ = window.set_timeout_with_callback(focus.unchecked_ref());
+    }
+}
+
 /// convert Widget into an equivalent html node
-fn widget_to_html<MSG>(widget: &Widget, attrs: Vec<Attribute<MSG>>) -> sauron::Node<MSG>
+fn widget_to_html<MSG>(
+    widget: &Widget,
+    attrs: Vec<Attribute<MSG>>,
+    themed: bool,
+) -> sauron::Node<MSG>
 where
     MSG: Clone + Debug + 'static,
 {
+    let mut node_styles = sizing_styles(&Sizing::from_attrs(&attrs));
+    node_styles.extend(color_styles(&attrs));
+    // without a theme the controls keep the look of the browser
+    let (control_styles, input_styles): (&[_], &[_]) = if themed {
+        (&THEMED_CONTROL, &THEMED_INPUT)
+    } else {
+        (&[], &[])
+    };
+    let is_disabled = find_value(AttribKey::Disabled, &attrs)
+        .map(|v| v.as_bool())
+        .flatten()
+        .unwrap_or(false);
+    if is_disabled {
+        // greys out any widget and blocks the pointer events of those
+        // which have no disabled attribute in html, such as the expander
+        node_styles.push(("opacity", "0.5".to_string()));
+        node_styles.push(("pointer-events", "none".to_string()));
+    }
+    let is_visible = find_value(AttribKey::Visible, &attrs)
+        .map(|v| v.as_bool())
+        .flatten()
+        .unwrap_or(true);
+    if !is_visible {
+        node_styles.push(("display", "none".to_string()));
+    }
+    let disabled_flag = || attrs_flag([("disabled", "disabled", is_disabled)]);
+    let alignment = find_value(AttribKey::Alignment, &attrs)
+        .map(Alignment::from_value)
+        .flatten();
+    let cross_alignment = find_value(AttribKey::CrossAlignment, &attrs)
+        .map(Alignment::from_value)
+        .flatten();
     match widget {
-        Widget::Vbox => div(
-            vec![styles(vec![
-                ("display", "flex"),
-                ("flex-direction", "column"),
-            ])],
-            vec![],
-        ),
-        Widget::Hbox => div(
-            vec![styles(vec![("display", "flex"), ("flex-direction", "row")])],
+        Widget::Vbox | Widget::Hbox => {
+            let direction = if *widget == Widget::Vbox {
+                "column"
+            } else {
+                "row"
+            };
+            let mut flex_styles = vec![("display", "flex"), ("flex-direction", direction)];
+            if let Some(alignment) = alignment {
+                flex_styles.push(("justify-content", flex_alignment(alignment)));
+            }
+            if let Some(cross_alignment) = cross_alignment {
+                // the free space can only be distributed along the direction
+                let align_items = match cross_alignment {
+                    Alignment::SpaceBetween => "stretch",
+                    _ => flex_alignment(cross_alignment),
+                };
+                flex_styles.push(("align-items", align_items));
+            }
+            div(vec![styles_with(&flex_styles, &node_styles)], vec![])
+        }
+        Widget::Frame => {
+            let title = find_value(AttribKey::Label, &attrs)
+                .map(|v| v.to_string())
+                .unwrap_or(String::new());
+            let border_style = find_value(AttribKey::BorderStyle, &attrs)
+                .map(BorderStyle::from_value)
+                .flatten()
+                .unwrap_or_default();
+            // the children of the frame are appended after the legend
+            fieldset(
+                vec![styles_with(
+                    &[
+                        ("display", "flex"),
+                        ("flex-direction", "column"),
+                        ("border-style", border_style.as_str()),
+                    ],
+                    &node_styles,
+                )],
+                vec![legend(vec![], vec![text(title)])],
+            )
+            // a disabled fieldset also disables the controls inside it
+            .add_attributes(disabled_flag())
+        }
+        Widget::Separator(Orientation::Horizontal) => {
+            hr(vec![styles_with(&[("width", "100%")], &node_styles)], vec![])
+        }
+        Widget::Separator(Orientation::Vertical) => div(
+            vec![styles_with(
+                &[("border-left", "1px solid"), ("align-self", "stretch")],
+                &node_styles,
+            )],
             vec![],
         ),
+        Widget::Expander => {
+            let header = find_value(AttribKey::Label, &attrs)
+                .map(|v| v.to_string())
+                .unwrap_or(String::new());
+            let expanded = find_value(AttribKey::Expanded, &attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
+            let open = attrs_flag([("open", "open", expanded)]);
+
+            let attributes = attrs
+                .into_iter()
+                .filter_map(|att| match att.name {
+                    AttribKey::ToggleEvent => att.take_callback().map(|cb| {
+                        on("toggle", move |ev: web_sys::Event| {
+                            let is_open = ev
+                                .target()
+                                .map(|target| target.dyn_into::<HtmlDetailsElement>().ok())
+                                .flatten()
+                                .map(|details| details.open())
+                                .unwrap_or(false);
+                            cb.emit(InputEvent::new(is_open.to_string()))
+                        })
+                    }),
+                    _ => None,
+                })
+                .collect();
+            // the children of the expander are appended after the summary
+            details(
+                vec![styles_with(&[], &node_styles)],
+                vec![summary(vec![], vec![text(header)])],
+            )
+            .add_attributes(open)
+            .add_attributes(attributes)
+        }
         Widget::Button => {
             let label = find_value(AttribKey::Label, &attrs)
                 .map(|v| v.to_string())
                 .unwrap_or(String::new());
 
+            let btn_icon = find_value(AttribKey::Icon, &attrs)
+                .map(Icon::from_value)
+                .flatten();
+
             let attributes = attrs
                 .into_iter()
                 .filter_map(|att| match att.name {
@@ -102,13 +552,45 @@ where
                     _ => None,
                 })
                 .collect();
-            input(vec![r#type("button"), value(label)], vec![]).add_attributes(attributes)
+            if let Some(btn_icon) = btn_icon {
+                // an input can not have children, so a button
+                // element is used to place the icon next to the label
+                button(
+                    vec![styles_with(control_styles, &node_styles)],
+                    vec![icon_svg(btn_icon, IconSize::Small, &[]), text(label)],
+                )
+                .add_attributes(disabled_flag())
+                .add_attributes(attributes)
+            } else {
+                input(
+                    vec![
+                        r#type("button"),
+                        value(label),
+                        styles_with(control_styles, &node_styles),
+                    ],
+                    vec![],
+                )
+                .add_attributes(disabled_flag())
+                .add_attributes(attributes)
+            }
+        }
+        Widget::Text(txt) => {
+            let text_styles = match alignment {
+                Some(alignment) => vec![
+                    ("display", "block"),
+                    ("text-align", text_alignment(alignment)),
+                ],
+                None => vec![],
+            };
+            label(vec![styles_with(&text_styles, &node_styles)], vec![text(txt)])
         }
-        Widget::Text(txt) => label(vec![], vec![text(txt)]),
         Widget::TextInput => {
             let txt_value = find_value(AttribKey::Value, &attrs)
                 .map(|v| v.to_string())
                 .unwrap_or(String::new());
+            let txt_placeholder = find_value(AttribKey::Placeholder, &attrs)
+                .map(|v| v.to_string())
+                .unwrap_or(String::new());
             let attributes = attrs
                 .into_iter()
                 .filter_map(|att| match att.name {
@@ -118,7 +600,17 @@ where
                     _ => None,
                 })
                 .collect();
-            input(vec![r#type("text"), value(txt_value)], vec![]).add_attributes(attributes)
+            input(
+                vec![
+                    r#type("text"),
+                    value(txt_value),
+                    attr("placeholder", txt_placeholder),
+                    styles_with(input_styles, &node_styles),
+                ],
+                vec![],
+            )
+            .add_attributes(disabled_flag())
+            .add_attributes(attributes)
         }
         Widget::Checkbox => {
             let cb_label = find_value(AttribKey::Label, &attrs)
@@ -131,9 +623,12 @@ where
             let checked = attrs_flag([("checked", "checked", cb_value)]);
 
             div(
-                vec![],
+                vec![styles_with(&[], &node_styles)],
                 vec![
-                    input(vec![type_("checkbox")], vec![]).add_attributes(checked),
+                    input(vec![type_("checkbox")], vec![])
+                        .add_attributes(checked)
+                        .add_attributes(disabled_flag())
+                        .add_attributes(change_events(attrs)),
                     label(vec![], vec![text(cb_label)]),
                 ],
             )
@@ -148,13 +643,20 @@ where
                 .unwrap_or(false);
             let checked = attrs_flag([("checked", "checked", cb_value)]);
             div(
-                vec![],
+                vec![styles_with(&[], &node_styles)],
                 vec![
-                    input(vec![type_("radio")], vec![]).add_attributes(checked),
+                    input(vec![type_("radio")], vec![])
+                        .add_attributes(checked)
+                        .add_attributes(disabled_flag())
+                        .add_attributes(change_events(attrs)),
                     label(vec![], vec![text(cb_label)]),
                 ],
             )
         }
+        Widget::Icon(icon, size) => icon_svg(*icon, *size, &node_styles),
+        Widget::LineChart(chart) => chart_svg(chart, false, &node_styles),
+        Widget::BarChart(chart) => chart_svg(chart, true, &node_styles),
+        Widget::Sparkline(series) => sparkline_svg(series, &node_styles),
         Widget::Image(image) => {
             let mime_type = if let Some(mime) = image_mime(&image) {
                 mime
@@ -163,11 +665,14 @@ where
             };
             img(
                 vec![
-                    styles([
-                        ("width", "100%"),
-                        ("height", "auto"),
-                        ("max-width", "800px"),
-                    ]),
+                    styles_with(
+                        &[
+                            ("width", "100%"),
+                            ("height", "auto"),
+                            ("max-width", "800px"),
+                        ],
+                        &node_styles,
+                    ),
                     src(format!(
                         "data:{};base64,{}",
                         mime_type,
@@ -180,6 +685,320 @@ where
     }
 }
 
+/// the styles of buttons, using the css variables of the theme
+const THEMED_CONTROL: [(&str, &str); 5] = [
+    ("background-color", "var(--primary)"),
+    ("color", "var(--on-primary)"),
+    ("border", "1px solid var(--border)"),
+    ("border-radius", "var(--border-radius)"),
+    ("padding", "var(--spacing)"),
+];
+
+/// the styles of text inputs, using the css variables of the theme
+const THEMED_INPUT: [(&str, &str); 5] = [
+    ("background-color", "var(--background)"),
+    ("color", "var(--foreground)"),
+    ("border", "1px solid var(--border)"),
+    ("border-radius", "var(--border-radius)"),
+    ("padding", "var(--spacing)"),
+];
+
+/// the theme as css variables which are used by the widgets,
+/// together with the colors and font of the whole view
+fn theme_styles(theme: &Theme) -> Vec<(&'static str, String)> {
+    let palette = &theme.palette;
+    vec![
+        ("--background", palette.background.to_hex()),
+        ("--foreground", palette.foreground.to_hex()),
+        ("--primary", palette.primary.to_hex()),
+        ("--on-primary", palette.on_primary.to_hex()),
+        ("--border", palette.border.to_hex()),
+        ("--font-family", theme.font_family.clone()),
+        ("--font-size", format!("{}px", theme.font_size)),
+        ("--spacing", format!("{}px", theme.spacing(1))),
+        ("--border-radius", format!("{}px", theme.border_radius)),
+        ("background-color", "var(--background)".to_string()),
+        ("color", "var(--foreground)".to_string()),
+        ("font-family", "var(--font-family)".to_string()),
+        ("font-size", "var(--font-size)".to_string()),
+        ("min-height", "100vh".to_string()),
+    ]
+}
+
+/// the value of justify-content or align-items in a flex box
+fn flex_alignment(alignment: Alignment) -> &'static str {
+    match alignment {
+        Alignment::Start => "flex-start",
+        Alignment::Center => "center",
+        Alignment::End => "flex-end",
+        Alignment::Stretch => "stretch",
+        Alignment::SpaceBetween => "space-between",
+    }
+}
+
+fn text_alignment(alignment: Alignment) -> &'static str {
+    match alignment {
+        Alignment::Start => "left",
+        Alignment::Center => "center",
+        Alignment::End => "right",
+        Alignment::Stretch | Alignment::SpaceBetween => "justify",
+    }
+}
+
+/// the css properties of the color attributes
+fn color_styles<MSG>(attrs: &Vec<Attribute<MSG>>) -> Vec<(&'static str, String)>
+where
+    MSG: 'static,
+{
+    vec![
+        ("color", AttribKey::Color),
+        ("background-color", AttribKey::BackgroundColor),
+    ]
+    .into_iter()
+    .filter_map(|(name, key)| {
+        find_value(key, attrs)
+            .map(Color::from_value)
+            .flatten()
+            .map(|color| (name, color.to_hex()))
+    })
+    .collect()
+}
+
+/// the css properties of the sizing attributes
+fn sizing_styles(sizing: &Sizing) -> Vec<(&'static str, String)> {
+    let lengths = vec![
+        ("width", sizing.width),
+        ("height", sizing.height),
+        ("min-width", sizing.min_width),
+        ("min-height", sizing.min_height),
+        ("max-width", sizing.max_width),
+        ("max-height", sizing.max_height),
+    ];
+    let pixels = vec![
+        ("padding", sizing.padding),
+        ("margin", sizing.margin),
+        ("gap", sizing.spacing),
+    ];
+    let numbers = vec![
+        ("flex-grow", sizing.flex_grow),
+        ("flex-shrink", sizing.flex_shrink),
+    ];
+    lengths
+        .into_iter()
+        .filter_map(|(name, length)| length.map(|length| (name, length.to_css())))
+        .chain(
+            pixels
+                .into_iter()
+                .filter_map(|(name, px)| px.map(|px| (name, format!("{}px", px)))),
+        )
+        .chain(
+            numbers
+                .into_iter()
+                .filter_map(|(name, number)| number.map(|number| (name, number.to_string()))),
+        )
+        .collect()
+}
+
+/// a single style attribute with the styles of the widget itself,
+/// followed by the styles from the attributes of the node which override them
+fn styles_with<MSG>(
+    widget_styles: &[(&'static str, &str)],
+    sizing_styles: &[(&'static str, String)],
+) -> sauron::Attribute<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    let all_styles: Vec<(&'static str, String)> = widget_styles
+        .iter()
+        .map(|(name, value)| (*name, value.to_string()))
+        .chain(sizing_styles.iter().cloned())
+        .collect();
+    styles(all_styles)
+}
+
+/// an inline svg of the icon drawn in the current text color
+fn icon_svg<MSG>(icon: Icon, size: IconSize, sizing: &[(&'static str, String)]) -> sauron::Node<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    let pixels = size.pixels().to_string();
+    svg(
+        vec![
+            attr("viewBox", "0 0 24 24"),
+            attr("width", &pixels),
+            attr("height", &pixels),
+            attr("fill", "currentColor"),
+            styles_with(&[("vertical-align", "middle")], sizing),
+        ],
+        vec![path(vec![attr("d", icon_path(icon))], vec![])],
+    )
+}
+
+/// the svg path data of the icon, in a 24x24 view box
+fn icon_path(icon: Icon) -> &'static str {
+    match icon {
+        Icon::Save => "M17 3H5c-1.11 0-2 .9-2 2v14c0 1.1.89 2 2 2h14c1.1 0 2-.9 2-2V7l-4-4zm-5 16c-1.66 0-3-1.34-3-3s1.34-3 3-3 3 1.34 3 3-1.34 3-3 3zm3-10H5V5h10v4z",
+        Icon::Open => "M20 6h-8l-2-2H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2zm0 12H4V8h16v10z",
+        Icon::Delete => "M6 19c0 1.1.9 2 2 2h8c1.1 0 2-.9 2-2V7H6v12zM19 4h-3.5l-1-1h-5l-1 1H5v2h14V4z",
+        Icon::Warning => "M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z",
+        Icon::Error => "M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z",
+        Icon::Info => "M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-6h2v6zm0-8h-2V7h2v2z",
+        Icon::Add => "M19 13h-6v6h-2v-6H5v-2h6V5h2v6h6v2z",
+        Icon::Remove => "M19 13H5v-2h14v2z",
+        Icon::Edit => "M3 17.25V21h3.75L17.81 9.94l-3.75-3.75L3 17.25zM20.71 7.04c.39-.39.39-1.02 0-1.41l-2.34-2.34c-.39-.39-1.02-.39-1.41 0l-1.83 1.83 3.75 3.75 1.83-1.83z",
+        Icon::Search => "M15.5 14h-.79l-.28-.27C15.41 12.59 16 11.11 16 9.5 16 5.91 13.09 3 9.5 3S3 5.91 3 9.5 5.91 16 9.5 16c1.61 0 3.09-.59 4.23-1.57l.27.28v.79l5 4.99L20.49 19l-4.99-5zm-6 0C7.01 14 5 11.99 5 9.5S7.01 5 9.5 5 14 7.01 14 9.5 11.99 14 9.5 14z",
+        Icon::Close => "M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z",
+        Icon::Refresh => "M17.65 6.35C16.2 4.9 14.21 4 12 4c-4.42 0-7.99 3.58-7.99 8s3.57 8 7.99 8c3.73 0 6.84-2.55 7.73-6h-2.08c-.82 2.33-3.04 4-5.65 4-3.31 0-6-2.69-6-6s2.69-6 6-6c1.66 0 3.14.69 4.22 1.78L13 11h7V4l-2.35 2.35z",
+    }
+}
+
+const CHART_WIDTH: f64 = 400.0;
+const CHART_HEIGHT: f64 = 200.0;
+/// space at the left and bottom of the chart for the axis labels
+const CHART_MARGIN: f64 = 30.0;
+
+/// draw the line or bar chart as an svg,
+/// the chart is scaled to the width of its container
+fn chart_svg<MSG>(chart: &Chart, bars: bool, sizing: &[(&'static str, String)]) -> sauron::Node<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    let bounds = chart.bounds();
+    let point_count = std::cmp::max(chart.point_count(), 1);
+    let plot_height = CHART_HEIGHT - CHART_MARGIN;
+    let step = (CHART_WIDTH - CHART_MARGIN) / point_count as f64;
+    // the y coordinate of a value normalized to 0.0..=1.0
+    let y_of = |v: f64| plot_height * (1.0 - v);
+    let baseline = y_of(-bounds.0 / (bounds.1 - bounds.0).max(std::f64::EPSILON));
+
+    let mut children = vec![
+        line(
+            vec![
+                attr("x1", CHART_MARGIN),
+                attr("y1", 0),
+                attr("x2", CHART_MARGIN),
+                attr("y2", plot_height),
+                attr("stroke", "currentColor"),
+            ],
+            vec![],
+        ),
+        line(
+            vec![
+                attr("x1", CHART_MARGIN),
+                attr("y1", baseline),
+                attr("x2", CHART_WIDTH),
+                attr("y2", baseline),
+                attr("stroke", "currentColor"),
+            ],
+            vec![],
+        ),
+    ];
+
+    let series_count = std::cmp::max(chart.series.len(), 1) as f64;
+    for (s, series) in chart.series.iter().enumerate() {
+        let normalized = series.normalized(bounds);
+        if bars {
+            let bar_width = step * 0.8 / series_count;
+            for (i, v) in normalized.iter().enumerate() {
+                let y = y_of(*v);
+                children.push(rect(
+                    vec![
+                        attr(
+                            "x",
+                            CHART_MARGIN + step * (i as f64 + 0.1) + bar_width * s as f64,
+                        ),
+                        attr("y", y.min(baseline)),
+                        attr("width", bar_width),
+                        attr("height", (baseline - y).abs()),
+                        attr("fill", series.color.to_hex()),
+                    ],
+                    vec![],
+                ));
+            }
+        } else {
+            let points: Vec<String> = normalized
+                .iter()
+                .enumerate()
+                .map(|(i, v)| format!("{},{}", CHART_MARGIN + step * (i as f64 + 0.5), y_of(*v)))
+                .collect();
+            children.push(polyline(
+                vec![
+                    attr("points", points.join(" ")),
+                    attr("fill", "none"),
+                    attr("stroke", series.color.to_hex()),
+                    attr("stroke-width", 2),
+                ],
+                vec![],
+            ));
+        }
+    }
+
+    for (i, label) in chart.x_labels.iter().enumerate() {
+        children.push(svg_text(
+            vec![
+                attr("x", CHART_MARGIN + step * (i as f64 + 0.5)),
+                attr("y", CHART_HEIGHT - 8.0),
+                attr("text-anchor", "middle"),
+                attr("font-size", 10),
+                attr("fill", "currentColor"),
+            ],
+            vec![text(label)],
+        ));
+    }
+
+    let y_step = plot_height / std::cmp::max(chart.y_labels.len() as i32 - 1, 1) as f64;
+    for (i, label) in chart.y_labels.iter().enumerate() {
+        children.push(svg_text(
+            vec![
+                attr("x", CHART_MARGIN - 4.0),
+                attr("y", plot_height - y_step * i as f64),
+                attr("text-anchor", "end"),
+                attr("font-size", 10),
+                attr("fill", "currentColor"),
+            ],
+            vec![text(label)],
+        ));
+    }
+
+    svg(
+        vec![
+            attr("viewBox", format!("0 0 {} {}", CHART_WIDTH, CHART_HEIGHT)),
+            styles_with(&[("width", "100%"), ("height", "auto")], sizing),
+        ],
+        children,
+    )
+}
+
+/// draw the series as a small line the size of the text
+fn sparkline_svg<MSG>(series: &Series, sizing: &[(&'static str, String)]) -> sauron::Node<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    let normalized = series.normalized(series.bounds());
+    let step = 100.0 / std::cmp::max(normalized.len() as i32 - 1, 1) as f64;
+    let points: Vec<String> = normalized
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{},{}", step * i as f64, 20.0 * (1.0 - v)))
+        .collect();
+    svg(
+        vec![
+            attr("viewBox", "0 0 100 20"),
+            attr("preserveAspectRatio", "none"),
+            styles_with(&[("width", "100px"), ("height", "1em")], sizing),
+        ],
+        vec![polyline(
+            vec![
+                attr("points", points.join(" ")),
+                attr("fill", "none"),
+                attr("stroke", series.color.to_hex()),
+                attr("vector-effect", "non-scaling-stroke"),
+            ],
+            vec![],
+        )],
+    )
+}
+
 fn image_mime(bytes: &[u8]) -> Option<String> {
     if let Some(format) = image::guess_format(&bytes).ok() {
         match format {
@@ -192,20 +1011,266 @@ fn image_mime(bytes: &[u8]) -> Option<String> {
     }
 }
 
-/// converts widget virtual node tree into an html node tree
-pub fn widget_tree_to_html_node<MSG>(widget_node: crate::Node<MSG>) -> sauron::Node<MSG>
+/// the toggle event of a checkbox or a radio with its new checked state
+fn change_events<MSG>(attrs: Vec<Attribute<MSG>>) -> Vec<sauron::Attribute<MSG>>
+where
+    MSG: Clone + Debug + 'static,
+{
+    attrs
+        .into_iter()
+        .filter_map(|att| match att.name {
+            AttribKey::ToggleEvent => att.take_callback().map(|cb| {
+                on("change", move |ev: web_sys::Event| {
+                    let is_checked = ev
+                        .target()
+                        .map(|target| target.dyn_into::<HtmlInputElement>().ok())
+                        .flatten()
+                        .map(|input| input.checked())
+                        .unwrap_or(false);
+                    cb.emit(InputEvent::new(is_checked.to_string()))
+                })
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// the aria attributes of the widget, an image takes its accessible label
+/// as the alternative text and a button showing only an icon is named after the icon
+fn accessible_attributes<MSG>(
+    widget: &Widget,
+    attrs: &Vec<Attribute<MSG>>,
+) -> Vec<sauron::Attribute<MSG>>
+where
+    MSG: 'static,
+{
+    let string_value = |key| find_value(key, attrs).map(|v| v.to_string());
+    let icon_only = *widget == Widget::Button
+        && string_value(AttribKey::Label).map_or(true, |label| label.is_empty());
+    let accessible_label = string_value(AttribKey::AccessibleLabel).or_else(|| {
+        find_value(AttribKey::Icon, attrs)
+            .map(Icon::from_value)
+            .flatten()
+            .filter(|_| icon_only)
+            .map(|icon| icon.as_str().to_string())
+    });
+    let mut attributes = vec![];
+    match (widget, accessible_label) {
+        // an image without an alternative text is decorative
+        (Widget::Image(_), label) => attributes.push(attr("alt", label.unwrap_or_default())),
+        (_, Some(label)) => attributes.push(attr("aria-label", label)),
+        (_, None) => (),
+    }
+    if let Some(description) = string_value(AttribKey::AccessibleDescription) {
+        attributes.push(attr("aria-description", description));
+    }
+    if let Some(role) = string_value(AttribKey::Role) {
+        attributes.push(attr("role", role));
+    }
+    if let Some(politeness) = find_value(AttribKey::Live, attrs)
+        .map(Politeness::from_value)
+        .flatten()
+    {
+        attributes.push(attr("aria-live", politeness.as_str()));
+    }
+    attributes
+}
+
+fn is_common_event(key: &AttribKey) -> bool {
+    matches!(
+        key,
+        AttribKey::KeyPressEvent
+            | AttribKey::KeyReleaseEvent
+            | AttribKey::FocusEvent
+            | AttribKey::BlurEvent
+            | AttribKey::DoubleClickEvent
+            | AttribKey::MouseEnterEvent
+            | AttribKey::MouseLeaveEvent
+            | AttribKey::MouseMoveEvent
+            | AttribKey::WheelEvent
+    )
+}
+
+/// the keys are named by the browser in the key of the keyboard event,
+/// the mouse events are converted here so the coordinate is relative to the widget
+fn common_event<MSG>(
+    key: AttribKey,
+    cb: Callback<sauron_vdom::Event, MSG>,
+) -> sauron::Attribute<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    let event_name = match key {
+        AttribKey::KeyPressEvent => return onkeydown(move |ev| cb.emit(ev)),
+        AttribKey::KeyReleaseEvent => return onkeyup(move |ev| cb.emit(ev)),
+        AttribKey::FocusEvent => {
+            return on("focus", move |ev: web_sys::Event| cb.emit(target_value(ev)))
+        }
+        AttribKey::BlurEvent => {
+            return on("blur", move |ev: web_sys::Event| cb.emit(target_value(ev)))
+        }
+        AttribKey::DoubleClickEvent => "dblclick",
+        AttribKey::MouseEnterEvent => "mouseenter",
+        AttribKey::MouseLeaveEvent => "mouseleave",
+        AttribKey::MouseMoveEvent => "mousemove",
+        AttribKey::WheelEvent => "wheel",
+        _ => unreachable!("not a common event: {}", key),
+    };
+    on(event_name, move |ev: web_sys::Event| {
+        cb.emit(to_mouse_event(event_name, ev))
+    })
+}
+
+/// the custom attribute set as is, or listening to the event of this name
+fn custom_attribute<MSG>(
+    name: &'static str,
+    att: Attribute<MSG>,
+) -> Option<sauron::Attribute<MSG>>
+where
+    MSG: Clone + Debug + 'static,
+{
+    if let Some(value) = att.get_value() {
+        return Some(attr(name, value.to_string()));
+    }
+    att.take_callback().map(|cb| {
+        on(name, move |ev: web_sys::Event| {
+            if ev.dyn_ref::<web_sys::MouseEvent>().is_some() {
+                cb.emit(to_mouse_event(name, ev))
+            } else {
+                cb.emit(target_value(ev))
+            }
+        })
+    })
+}
+
+thread_local! {
+    /// the names of the custom attributes, which sauron needs as static strings
+    static CUSTOM_NAMES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+/// a static string of the custom attribute name, each distinct name is leaked once
+fn intern(name: &str) -> &'static str {
+    CUSTOM_NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+        if let Some(interned) = names.get(name) {
+            return *interned;
+        }
+        let interned: &'static str = Box::leak(name.to_string().into_boxed_str());
+        names.insert(interned);
+        interned
+    })
+}
+
+/// the value of the element the event is dispatched to,
+/// such as the input which takes or loses the focus
+fn target_value(ev: web_sys::Event) -> InputEvent {
+    let value = ev
+        .target()
+        .map(|target| target.dyn_into::<HtmlInputElement>().ok())
+        .flatten()
+        .map(|input| input.value())
+        .unwrap_or_default();
+    InputEvent::new(value)
+}
+
+fn to_mouse_event(event_type: &'static str, ev: web_sys::Event) -> MouseEvent {
+    let buttons = if let Some(wheel_event) = ev.dyn_ref::<web_sys::WheelEvent>() {
+        if wheel_event.delta_y() < 0.0 {
+            MouseButton::WheelUp
+        } else {
+            MouseButton::WheelDown
+        }
+    } else {
+        match ev.dyn_ref::<web_sys::MouseEvent>().map(|me| me.button()) {
+            Some(1) => MouseButton::Middle,
+            Some(2) => MouseButton::Right,
+            _ => MouseButton::Left,
+        }
+    };
+    let coordinate = ev
+        .dyn_ref::<web_sys::MouseEvent>()
+        .map(|me| Coordinate::new(me.offset_x(), me.offset_y()))
+        .unwrap_or_default();
+    MouseEvent {
+        r#type: event_type,
+        coordinate,
+        buttons,
+        ..Default::default()
+    }
+}
+
+/// converts widget virtual node tree into an html node tree,
+/// the buttons and inputs are styled with the css variables of the theme when `themed`
+pub fn widget_tree_to_html_node<MSG>(
+    widget_node: crate::Node<MSG>,
+    themed: bool,
+) -> sauron::Node<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    to_html_node(widget_node, themed, &mut vec![])
+}
+
+/// the element of each widget is marked with the path of child indices leading to it
+fn to_html_node<MSG>(
+    widget_node: crate::Node<MSG>,
+    themed: bool,
+    path: &mut Vec<usize>,
+) -> sauron::Node<MSG>
 where
     MSG: Clone + Debug + 'static,
 {
     match widget_node {
         crate::Node::Element(widget) => {
+            // the key, focus and pointer events, the custom html attributes, the id,
+            // the classes, the autofocus and the aria attributes are the same for every widget
+            let (common_attrs, attrs): (Vec<_>, Vec<_>) =
+                widget.attrs.into_iter().partition(|att| {
+                    is_common_event(&att.name) || att.name.custom_name("html").is_some()
+                });
+            let mut common_attributes: Vec<sauron::Attribute<MSG>> = common_attrs
+                .into_iter()
+                .filter_map(|att| match att.name.custom_name("html").map(intern) {
+                    Some(name) => custom_attribute(name, att),
+                    None => {
+                        let name = att.name.clone();
+                        att.take_callback().map(|cb| common_event(name, cb))
+                    }
+                })
+                .collect();
+            if let Some(widget_id) = find_value(AttribKey::Id, &attrs) {
+                common_attributes.push(attr("id", widget_id.to_string()));
+            }
+            if let Some(classes) = find_value(AttribKey::Class, &attrs) {
+                common_attributes.push(attr("class", classes.to_string()));
+            }
+            let autofocus = find_value(AttribKey::Autofocus, &attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
+            common_attributes.extend(attrs_flag([("autofocus", "autofocus", autofocus)]));
+            common_attributes.extend(accessible_attributes(&widget.tag, &attrs));
+            let indices: Vec<String> = path.iter().map(|index| index.to_string()).collect();
+            let path_attribute = || attr(PATH_ATTRIBUTE, indices.join("."));
+            common_attributes.push(path_attribute());
             // convert the Widget tag to html node
-            let mut html_node: sauron::Node<MSG> = widget_to_html(&widget.tag, widget.attrs);
+            let html_node: sauron::Node<MSG> = widget_to_html(&widget.tag, attrs, themed);
+            let mut html_node = match widget.tag {
+                // the wrapper is also marked with the path so hovering its label counts
+                Widget::Checkbox | Widget::Radio => {
+                    add_to_input(html_node, common_attributes)
+                        .add_attributes(vec![path_attribute()])
+                }
+                _ => html_node.add_attributes(common_attributes),
+            };
             // cast the html node to element
             if let Some(html_element) = html_node.as_element_mut() {
-                for widget_child in widget.children {
+                for (index, widget_child) in widget.children.into_iter().enumerate() {
                     // convert all widget child to an html child node
-                    let mut html_child: sauron::Node<MSG> = widget_tree_to_html_node(widget_child);
+                    path.push(index);
+                    let html_child: sauron::Node<MSG> =
+                        to_html_node(widget_child, themed, path);
+                    path.pop();
                     html_element.children.push(html_child);
                 }
             }
@@ -214,3 +1279,20 @@ where
         crate::Node::Text(txt) => text(txt.text),
     }
 }
+
+/// a checkbox or radio is an input wrapped with its label, the attributes go on the input
+/// since it is the one which takes the focus and is named to the assistive technologies
+fn add_to_input<MSG>(
+    mut wrapper: sauron::Node<MSG>,
+    attributes: Vec<sauron::Attribute<MSG>>,
+) -> sauron::Node<MSG>
+where
+    MSG: Clone + Debug + 'static,
+{
+    if let Some(wrapper_element) = wrapper.as_element_mut() {
+        if let Some(sauron::Node::Element(input)) = wrapper_element.children.first_mut() {
+            input.attrs.extend(attributes);
+        }
+    }
+    wrapper
+}
@@ -5,11 +5,17 @@ use crate::{
 use image::{bmp::BMPEncoder, ColorType, GenericImageView, ImageEncoder};
 use native_windows_gui as nwg;
 use nwg::{
-    Bitmap, BoxLayout, Button, CheckBox, ControlHandle, ImageDecoder, ImageFrame, Label,
-    RadioButton, TextInput, Window,
+    Bitmap, BoxLayout, Button, CheckBox, CheckBoxState, ControlHandle, ImageDecoder, ImageFrame,
+    Label, RadioButton, RadioButtonState, TextInput, Window,
+};
+use sauron_vdom::{event::InputEvent, Dispatch};
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    rc::Rc,
 };
-use sauron_vdom::Dispatch;
-use std::{cell::RefCell, fmt, fmt::Debug, marker::PhantomData, rc::Rc};
 
 pub struct NwgBackend<APP, MSG>
 where
@@ -30,8 +36,9 @@ impl<APP, MSG> NwgBackend<APP, MSG> {
     {
         println!("1 new");
         nwg::init().expect("Failed to init Native Windows GUI");
-        let current_vdom = app.view();
-        let root_vdom = app.view();
+        let style_sheet = app.style_sheet();
+        let current_vdom = style_sheet.apply(app.view());
+        let root_vdom = style_sheet.apply(app.view());
 
         let mut window: Window = Window::default();
         Window::builder()
@@ -202,7 +209,7 @@ impl NwgWidget {
     {
         println!("from node...");
         match widget {
-            Widget::Vbox => {
+            Widget::Vbox | Widget::Frame | Widget::Expander => {
                 println!("vbox..");
                 let mut box_layout = BoxLayout::default();
 
@@ -263,17 +270,72 @@ impl NwgWidget {
 
                 NwgWidget::Text(label)
             }
+            Widget::Separator(_orientation) => {
+                let mut label = Label::default();
+
+                Label::builder()
+                    .size((280, 2))
+                    .text("")
+                    .parent(window)
+                    .build(&mut label)
+                    .expect("must build separator");
+
+                NwgWidget::Text(label)
+            }
+            Widget::Icon(icon, _size) => {
+                let mut label = Label::default();
+
+                Label::builder()
+                    .size((24, 24))
+                    .text(icon.glyph())
+                    .parent(window)
+                    .build(&mut label)
+                    .expect("must build icon");
+
+                NwgWidget::Text(label)
+            }
+            Widget::LineChart(chart) | Widget::BarChart(chart) => {
+                let lines: Vec<String> = chart
+                    .series
+                    .iter()
+                    .map(|series| format!("{} {}", series.to_block_chars(), series.name))
+                    .collect();
+                let mut label = Label::default();
+
+                Label::builder()
+                    .size((280, 60))
+                    .text(&lines.join("\r\n"))
+                    .parent(window)
+                    .build(&mut label)
+                    .expect("must build chart");
+
+                NwgWidget::Text(label)
+            }
+            Widget::Sparkline(series) => {
+                let mut label = Label::default();
+
+                Label::builder()
+                    .size((280, 20))
+                    .text(&series.to_block_chars())
+                    .parent(window)
+                    .build(&mut label)
+                    .expect("must build sparkline");
+
+                NwgWidget::Text(label)
+            }
             Widget::TextInput => {
                 println!("textinput..");
                 let value = find_value(AttribKey::Value, &attrs)
                     .map(|v| v.to_string())
                     .unwrap_or(String::new());
+                let placeholder = find_value(AttribKey::Placeholder, &attrs).map(|v| v.to_string());
 
                 let mut text_input = TextInput::default();
 
                 TextInput::builder()
                     .size((280, 60))
                     .text(&value)
+                    .placeholder_text(placeholder.as_deref())
                     .parent(window)
                     .build(&mut text_input)
                     .expect("must build label");
@@ -292,14 +354,21 @@ impl NwgWidget {
                     .flatten()
                     .unwrap_or(false);
 
+                let check_state = if value {
+                    CheckBoxState::Checked
+                } else {
+                    CheckBoxState::Unchecked
+                };
                 let mut checkbox = CheckBox::default();
                 CheckBox::builder()
                     .size((280, 60))
                     .text(&label)
+                    .check_state(check_state)
                     .parent(window)
                     .build(&mut checkbox)
                     .expect("must build checkbox");
 
+                bind_toggle(window, &checkbox.handle, program, &attrs, value, false);
                 NwgWidget::Checkbox(checkbox)
             }
             Widget::Radio => {
@@ -313,14 +382,21 @@ impl NwgWidget {
                     .flatten()
                     .unwrap_or(false);
 
+                let check_state = if value {
+                    RadioButtonState::Checked
+                } else {
+                    RadioButtonState::Unchecked
+                };
                 let mut radio = RadioButton::default();
                 RadioButton::builder()
                     .size((280, 60))
                     .text(&label)
+                    .check_state(check_state)
                     .parent(window)
                     .build(&mut radio)
                     .expect("must build checkbox");
 
+                bind_toggle(window, &radio.handle, program, &attrs, value, true);
                 NwgWidget::Radio(radio)
             }
             Widget::Image(blob) => {
@@ -377,3 +453,31 @@ impl NwgWidget {
         }
     }
 }
+
+/// clicking a checkbox or a radio emits its toggle event with its new checked state,
+/// a radio stays checked when it is clicked again
+fn bind_toggle<MSG, DSP>(
+    window: &Window,
+    handle: &ControlHandle,
+    program: &Rc<DSP>,
+    attrs: &Vec<Attribute<MSG>>,
+    checked: bool,
+    is_radio: bool,
+) where
+    MSG: Debug + 'static,
+    DSP: Dispatch<MSG> + 'static,
+{
+    if let Some(cb) = find_callback(AttribKey::ToggleEvent, attrs) {
+        let cb_clone = cb.clone();
+        let program_clone = Rc::clone(program);
+        let control_handle = *handle;
+        let checked = Cell::new(checked);
+        nwg::bind_event_handler(handle, &window.handle, move |evt, _evt_data, evt_handle| {
+            if let (nwg::Event::OnButtonClick, true) = (evt, evt_handle == control_handle) {
+                checked.set(is_radio || !checked.get());
+                let msg = cb_clone.emit(InputEvent::new(checked.get().to_string()));
+                program_clone.dispatch(msg);
+            }
+        });
+    }
+}
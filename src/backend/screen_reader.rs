@@ -0,0 +1,246 @@
+//! A plain text rendering of the view for the terminal backends,
+//! one line for each widget in reading order, naming its role, its accessible name
+//! and its state. A screen reader reads it line by line, which it can not do
+//! with the drawn layout. It is used in place of the layout when the
+//! `SAURON_SCREEN_READER` environment variable is set.
+//! The changes of the live regions are announced on a status line after the view.
+use crate::{
+    widget::{attribute::find_value, icon::Icon, property::Politeness},
+    AttribKey, Node, Widget,
+};
+use std::cell::RefCell;
+
+pub fn is_enabled() -> bool {
+    std::env::var_os("SAURON_SCREEN_READER").is_some()
+}
+
+/// the lines of the widgets in the view, the children of
+/// frames and expanders are indented under them
+pub fn lines<MSG>(node: &Node<MSG>) -> Vec<String>
+where
+    MSG: 'static,
+{
+    let mut lines = vec![];
+    add_lines(node, 0, false, &mut lines);
+    lines
+}
+
+fn add_lines<MSG>(node: &Node<MSG>, depth: usize, disabled: bool, lines: &mut Vec<String>)
+where
+    MSG: 'static,
+{
+    let element = match node {
+        Node::Element(element) => element,
+        Node::Text(txt) => {
+            lines.push(format!("{}{}", "  ".repeat(depth), txt.text));
+            return;
+        }
+    };
+    let string_value = |key| find_value(key, &element.attrs).map(|v| v.to_string());
+    let flag = |key| {
+        find_value(key, &element.attrs)
+            .map(|v| v.as_bool())
+            .flatten()
+    };
+    if flag(AttribKey::Visible) == Some(false) {
+        return;
+    }
+    let disabled = disabled || flag(AttribKey::Disabled) == Some(true);
+    let role = string_value(AttribKey::Role).or_else(|| default_role(&element.tag));
+
+    let mut states = vec![];
+    match element.tag {
+        Widget::Checkbox | Widget::Radio => {
+            if flag(AttribKey::Value) == Some(true) {
+                states.push("checked");
+            } else {
+                states.push("not checked");
+            }
+        }
+        Widget::Expander => {
+            if flag(AttribKey::Expanded) == Some(true) {
+                states.push("expanded");
+            } else {
+                states.push("collapsed");
+            }
+        }
+        _ => (),
+    }
+    if disabled {
+        states.push("disabled");
+    }
+    if flag(AttribKey::Focused) == Some(true) {
+        states.push("focused");
+    }
+
+    let name = string_value(AttribKey::AccessibleLabel)
+        .or_else(|| string_value(AttribKey::Label).filter(|label| !label.is_empty()))
+        .or_else(|| string_value(AttribKey::Placeholder))
+        .or_else(|| match &element.tag {
+            Widget::Text(txt) => Some(txt.to_string()),
+            Widget::Icon(icon, _) => Some(icon.as_str().to_string()),
+            // a button showing only an icon is named after the icon
+            _ => find_value(AttribKey::Icon, &element.attrs)
+                .map(Icon::from_value)
+                .flatten()
+                .map(|icon| icon.as_str().to_string()),
+        });
+    let value = match element.tag {
+        Widget::TextInput => string_value(AttribKey::Value),
+        _ => None,
+    };
+
+    let head = role
+        .into_iter()
+        .chain(states.into_iter().map(|state| state.to_string()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut line = [head, name.unwrap_or_default(), value.unwrap_or_default()]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(": ");
+    if let Some(description) = string_value(AttribKey::AccessibleDescription) {
+        line = format!("{} - {}", line, description);
+    }
+    // the rows and columns only arrange their children, they are not read
+    let child_depth = if line.is_empty() {
+        depth
+    } else {
+        lines.push(format!("{}{}", "  ".repeat(depth), line));
+        depth + 1
+    };
+    let collapsed = element.tag == Widget::Expander && flag(AttribKey::Expanded) != Some(true);
+    if !collapsed {
+        for child in element.children.iter() {
+            add_lines(child, child_depth, disabled, lines);
+        }
+    }
+}
+
+/// the role read for a widget, the text is read without one
+fn default_role(widget: &Widget) -> Option<String> {
+    let role = match widget {
+        Widget::Button => "button",
+        Widget::TextInput => "text input",
+        Widget::Checkbox => "checkbox",
+        Widget::Radio => "radio button",
+        Widget::Frame => "group",
+        Widget::Expander => "expander",
+        Widget::Image(_) | Widget::Icon(_, _) => "image",
+        Widget::Separator(_) => "separator",
+        Widget::LineChart(_) | Widget::BarChart(_) | Widget::Sparkline(_) => "chart",
+        Widget::Vbox | Widget::Hbox | Widget::Text(_) => return None,
+    };
+    Some(role.to_string())
+}
+
+/// the lines of the live regions in the view, unindented
+fn live_lines<MSG>(node: &Node<MSG>) -> Vec<String>
+where
+    MSG: 'static,
+{
+    let mut region_lines = vec![];
+    if let Node::Element(element) = node {
+        let visible = find_value(AttribKey::Visible, &element.attrs)
+            .map(|v| v.as_bool())
+            .flatten();
+        if visible == Some(false) {
+            return region_lines;
+        }
+        let politeness = find_value(AttribKey::Live, &element.attrs)
+            .map(Politeness::from_value)
+            .flatten();
+        match politeness {
+            Some(Politeness::Polite) | Some(Politeness::Assertive) => region_lines.extend(
+                lines(node)
+                    .into_iter()
+                    .map(|line| line.trim_start().to_string()),
+            ),
+            _ => {
+                for child in element.children.iter() {
+                    region_lines.extend(live_lines(child));
+                }
+            }
+        }
+    }
+    region_lines
+}
+
+/// the lines of the live regions at the last draw
+#[derive(Default)]
+pub struct LiveRegions {
+    lines: RefCell<Option<Vec<String>>>,
+}
+
+impl LiveRegions {
+    /// the status line announcing the lines of the live regions which changed
+    /// since the last draw, the content of the first draw is not announced
+    pub fn announce<MSG>(&self, view: &Node<MSG>) -> Option<String>
+    where
+        MSG: 'static,
+    {
+        let current = live_lines(view);
+        let previous = self.lines.replace(Some(current.clone()))?;
+        let changed: Vec<&str> = current
+            .iter()
+            .filter(|line| !previous.contains(line))
+            .map(String::as_str)
+            .collect();
+        if changed.is_empty() {
+            None
+        } else {
+            Some(format!("status: {}", changed.join("; ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{
+        attribute::{disabled, label, live, value, visible},
+        button, checkbox, column, text,
+    };
+
+    fn view(status: &str, politeness: Politeness, shown: bool) -> Node<()> {
+        column(
+            vec![],
+            vec![
+                button(vec![label("Save"), disabled(true)]),
+                checkbox(vec![label("Done"), value(true)]),
+                column(vec![live(politeness), visible(shown)], vec![text(status)]),
+            ],
+        )
+    }
+
+    #[test]
+    fn lines_name_the_role_and_state() {
+        let lines = lines(&view("Saved", Politeness::Off, true));
+        assert_eq!(lines, vec!["button, disabled: Save", "checkbox, checked: Done", "Saved"]);
+    }
+
+    #[test]
+    fn announce_the_changes_of_polite_and_assertive_regions() {
+        for politeness in [Politeness::Polite, Politeness::Assertive].iter() {
+            let live_regions = LiveRegions::default();
+            assert_eq!(live_regions.announce(&view("Saving", *politeness, true)), None);
+            assert_eq!(
+                live_regions.announce(&view("Saved", *politeness, true)),
+                Some("status: Saved".to_string())
+            );
+            assert_eq!(live_regions.announce(&view("Saved", *politeness, true)), None);
+        }
+    }
+
+    #[test]
+    fn no_announcement_of_off_or_hidden_regions() {
+        let live_regions = LiveRegions::default();
+        live_regions.announce(&view("Saving", Politeness::Off, true));
+        assert_eq!(live_regions.announce(&view("Saved", Politeness::Off, true)), None);
+        let live_regions = LiveRegions::default();
+        live_regions.announce(&view("Saving", Politeness::Polite, false));
+        assert_eq!(live_regions.announce(&view("Saved", Politeness::Polite, false)), None);
+    }
+}
@@ -1,20 +1,37 @@
-use crate::{widget::Widget, AttribKey, Attribute, Backend, Component, Node};
-use events::Events;
+use crate::{
+    backend::{
+        executor::Executor,
+        focus::Focus,
+        mark_state,
+        screen_reader::{self, LiveRegions},
+        target_callback,
+        timers::Timers,
+        PIXELS_PER_COLUMN,
+        PIXELS_PER_ROW,
+    },
+    cmd::Command,
+    widget::{property, Widget},
+    AttribKey, Attribute, Backend, Cmd, Component, Node, Sub, Theme,
+};
+use events::{Events, Input};
 use itui::{
     backend::TermionBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    widgets::{Block, Borders, Button, Paragraph, Text, Widget as TermWidget},
+    widgets::{
+        Axis, BarChart, Block, Borders, Button, Chart, Dataset, Marker, Paragraph, Sparkline, Text,
+        Widget as TermWidget,
+    },
     Frame, Terminal,
 };
 use nodes::TuiWidget;
 use sauron_vdom::{
     builder::element,
-    event::{KeyEvent, Modifier},
+    event::{Coordinate, KeyEvent, Modifier, MouseEvent},
     Event,
 };
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     fmt::Debug,
     io::{self, Stdout},
     marker::PhantomData,
@@ -42,6 +59,30 @@ type TermionTerminal =
 pub struct TuiBackend<APP, MSG> {
     terminal: Rc<RefCell<TermionTerminal>>,
     app: Rc<RefCell<APP>>,
+    /// the theme of the app, read again on every draw
+    theme: RefCell<Option<Theme>>,
+    /// the cell of the last pointer event, used to tell
+    /// when the pointer enters or leaves a widget
+    last_pointer: Cell<Option<(u16, u16)>>,
+    /// the areas of the widgets at the last draw with their path of child indices,
+    /// used to find the widget under the pointer
+    areas: RefCell<Vec<(Vec<usize>, Rect)>>,
+    /// the widget which has the focus, moved with Tab and Shift-Tab
+    focus: Focus<MSG>,
+    /// draw the view as lines of plain text for a screen reader
+    screen_reader: bool,
+    /// the live regions of the view, their changes are announced by the screen reader
+    live_regions: LiveRegions,
+    /// the commands from the updates, executed once the view is drawn
+    commands: RefCell<Vec<Command<MSG>>>,
+    /// set by a quit command, which ends the draw loop
+    quit: Cell<bool>,
+    /// the subscriptions of the app, read again after every update
+    subscriptions: RefCell<Sub<MSG>>,
+    timers: RefCell<Timers>,
+    /// the futures performed by the commands
+    executor: RefCell<Executor<MSG>>,
+    events: Events,
     _phantom_msg: PhantomData<MSG>,
 }
 
@@ -51,24 +92,126 @@ where
     MSG: 'static,
 {
     fn start_draw_loop(&self) {
-        let events = Events::new();
         loop {
             self.terminal.borrow_mut().draw(|mut frame| {
                 self.draw_ui(frame, &None);
             });
-            let event = events.next().ok();
+            self.execute_commands();
+            if self.quit.get() {
+                break;
+            }
+            let event = match self.next_event() {
+                Ok(event) => event,
+                Err(_) => break,
+            };
             if let Some(Event::KeyEvent(ref key)) = event {
                 // break on CTRL-C
                 if key.modifier == Modifier::ctrl() && key.key == "c" {
                     break;
                 }
             }
-            self.terminal.borrow_mut().draw(|mut frame| {
-                self.draw_ui(frame, &event);
-            });
+            if event.is_some() {
+                self.terminal.borrow_mut().draw(|mut frame| {
+                    self.draw_ui(frame, &event);
+                });
+            }
             if let Some(event) = event {
                 self.app.borrow_mut().on_event(event);
             }
+            self.execute_commands();
+            if self.quit.get() {
+                break;
+            }
+        }
+    }
+
+    /// wait for the next input event, the ticks and resizes in between
+    /// dispatch the messages of the subscriptions.
+    /// Returns None when the view is to be drawn again without an event
+    fn next_event(&self) -> Result<Option<Event>, mpsc::RecvError> {
+        loop {
+            let is_idle = self.timers.borrow().is_empty() && self.executor.borrow().is_empty();
+            self.events.set_ticking(!is_idle);
+            match self.events.next()? {
+                Input::Event(event) => return Ok(Some(event)),
+                Input::Tick => {
+                    if self.tick() {
+                        return Ok(None);
+                    }
+                }
+                Input::Resize(width, height) => {
+                    let width = (f64::from(width) * PIXELS_PER_COLUMN) as i32;
+                    let height = (f64::from(height) * PIXELS_PER_ROW) as i32;
+                    let messages = self.subscriptions.borrow().resize_messages(width, height);
+                    for msg in messages {
+                        self.dispatch(msg);
+                    }
+                    return Ok(None);
+                }
+                Input::Wake => {
+                    let messages = self.executor.borrow_mut().poll();
+                    if !messages.is_empty() {
+                        for msg in messages {
+                            self.dispatch(msg);
+                        }
+                        return Ok(None);
+                    }
+                }
+            }
+        }
+    }
+
+    /// fire the timers which are due and the animation frames,
+    /// returns whether any message was dispatched
+    fn tick(&self) -> bool {
+        let messages = self.timers.borrow_mut().fire(&self.subscriptions.borrow());
+        let dispatched = !messages.is_empty();
+        for msg in messages {
+            self.dispatch(msg);
+        }
+        dispatched
+    }
+
+    /// update the app, its commands are executed once the view is drawn
+    fn dispatch(&self, msg: MSG) {
+        let cmd = self.app.borrow_mut().update(msg);
+        self.after_update(cmd);
+    }
+
+    /// keep the command to execute once the view is drawn,
+    /// and read the subscriptions again
+    fn after_update(&self, cmd: Cmd<MSG>) {
+        self.commands.borrow_mut().extend(cmd.into_commands());
+        *self.subscriptions.borrow_mut() = self.app.borrow().subscriptions();
+        self.timers.borrow_mut().sync(&self.subscriptions.borrow());
+    }
+
+    /// execute the pending commands, including those from the messages of the effects
+    fn execute_commands(&self) {
+        loop {
+            let commands: Vec<Command<MSG>> = self.commands.borrow_mut().drain(..).collect();
+            if commands.is_empty() {
+                return;
+            }
+            for command in commands {
+                match command {
+                    Command::Effect(effect) => {
+                        if let Some(msg) = effect() {
+                            self.dispatch(msg);
+                        }
+                    }
+                    Command::Perform(future) => self.executor.borrow_mut().spawn(future),
+                    Command::Quit => self.quit.set(true),
+                    Command::Focus(id) => {
+                        let view = self.app.borrow().view();
+                        for msg in self.focus.focus_id(&view, &id) {
+                            self.dispatch(msg);
+                        }
+                    }
+                    // the content is not scrollable in the terminal
+                    Command::ScrollTo(_) => (),
+                }
+            }
         }
     }
 
@@ -77,82 +220,435 @@ where
         B: itui::backend::Backend,
     {
         let view = self.app.borrow().view();
+        let is_tab = self.update_focus(&view, event);
+        // the view is made again since moving the focus may have updated the app
+        let mut view = self.app.borrow().view();
+        if let Some(path) = self.focus.focused_path(&view) {
+            mark_state(&mut view, &path, AttribKey::Focused);
+        }
+        if let Some(path) = self.hovered_path(event) {
+            mark_state(&mut view, &path, AttribKey::Hovered);
+        }
+        let view = self.app.borrow().style_sheet().apply(view);
+        *self.theme.borrow_mut() = self.app.borrow().theme();
         let frame_size = frame.size();
-        let tui_view = nodes::convert_widget_node_tree_to_tui_widget(view);
-        self.draw_widget_node_tree(tui_view, &mut frame, frame_size, event);
+        // fill the whole terminal with the background of the theme
+        let mut background: itui::widgets::Block<MSG> = itui::widgets::Block::default()
+            .style(self.text_style())
+            .area(frame_size);
+        background.render(&mut frame);
+        if let (Some(Event::KeyEvent(key_event)), false) = (event, is_tab) {
+            // the key press is delivered to the focused widget,
+            // or to the root widget when none has the focus
+            let focused = self.focus.focused_element(&view);
+            if let Some(cb) = target_callback(AttribKey::KeyPressEvent, &view, focused) {
+                let msg = cb.emit(key_event.clone());
+                self.dispatch(msg);
+            }
+        }
+        if self.screen_reader {
+            let mut lines = screen_reader::lines(&view);
+            lines.extend(self.live_regions.announce(&view));
+            let lines: Vec<Text> = lines
+                .into_iter()
+                .map(|line| Text::raw(format!("{}\n", line)))
+                .collect();
+            let mut text: Paragraph<_, MSG> = Paragraph::new(lines.iter())
+                .style(self.text_style())
+                .area(frame_size);
+            text.render(&mut frame);
+        } else {
+            let tui_view = nodes::convert_widget_node_tree_to_tui_widget(view);
+            self.areas.borrow_mut().clear();
+            self.draw_widget_node_tree(tui_view, &mut frame, frame_size, &mut vec![], event);
+        }
+        if let Some(Event::MouseEvent(mouse_event)) = event {
+            let coordinate = &mouse_event.coordinate;
+            self.last_pointer
+                .set(Some((coordinate.x as u16, coordinate.y as u16)));
+        }
     }
+
+    /// the path of the innermost widget under the pointer, in the areas of the last draw
+    fn hovered_path(&self, event: &Option<Event>) -> Option<Vec<usize>> {
+        let pointer = match event {
+            Some(Event::MouseEvent(mouse_event)) => {
+                let coordinate = &mouse_event.coordinate;
+                Some((coordinate.x as u16, coordinate.y as u16))
+            }
+            _ => self.last_pointer.get(),
+        }?;
+        self.areas
+            .borrow()
+            .iter()
+            .filter(|(_, area)| area_contains(*area, pointer))
+            .max_by_key(|(path, _)| path.len())
+            .map(|(path, _)| path.clone())
+    }
+
+    /// the children of a layout are the children of its widget in the view,
+    /// so the path of child indices leading to each widget is followed along
     fn draw_widget_node_tree<B>(
         &self,
         tui_widget: TuiWidget<MSG>,
         frame: &mut Frame<B>,
         area: Rect,
+        path: &mut Vec<usize>,
         event: &Option<Event>,
     ) where
         MSG: 'static,
         B: itui::backend::Backend,
     {
+        self.areas.borrow_mut().push((path.clone(), area));
         match tui_widget {
             TuiWidget::Layout(layout) => {
+                if let Some(block) = &layout.block {
+                    let mut tui_block = self.to_tui_block(block, area, event);
+                    tui_block.render(frame);
+                }
+                let aligned = match layout.alignment {
+                    None | Some(property::Alignment::Stretch) => false,
+                    _ => true,
+                };
+                let mut constraints = layout.constraints;
+                // the space left after the children is taken by a filler,
+                // then the children are moved into it
+                if aligned {
+                    constraints.push(Constraint::Min(0));
+                }
                 let chunks = Layout::default()
-                    .direction(layout.direction)
-                    .constraints(layout.constraints)
+                    .direction(layout.direction.clone())
+                    .margin(layout.margin)
+                    .constraints(constraints)
                     .split(area);
+                let child_count = layout.children.len();
+                let free = match (aligned, chunks.get(child_count)) {
+                    (true, Some(filler)) => match layout.direction {
+                        Direction::Horizontal => filler.width,
+                        Direction::Vertical => filler.height,
+                    },
+                    _ => 0,
+                };
+                let cross_direction = match layout.direction {
+                    Direction::Horizontal => Direction::Vertical,
+                    Direction::Vertical => Direction::Horizontal,
+                };
                 for (i, child) in layout.children.into_iter().enumerate() {
-                    //TODO: calculate the chunks area for each
-                    // of the children, taking into account the preference/properties
-                    // set in each of the children
-                    self.draw_widget_node_tree(child, frame, chunks[i], event);
+                    let mut chunk = chunks[i];
+                    let offset = match layout.alignment {
+                        Some(property::Alignment::End) => free,
+                        Some(property::Alignment::Center) => free / 2,
+                        Some(property::Alignment::SpaceBetween) if child_count > 1 => {
+                            (u32::from(free) * i as u32 / (child_count as u32 - 1)) as u16
+                        }
+                        _ => 0,
+                    };
+                    let cross_length = match child.preferred_constraint(&cross_direction) {
+                        Some(Constraint::Length(length)) => Some(length),
+                        _ => None,
+                    };
+                    match layout.direction {
+                        Direction::Horizontal => {
+                            chunk.x += offset;
+                            if let (Some(cross_alignment), Some(length)) =
+                                (layout.cross_alignment, cross_length)
+                            {
+                                let (y, height) =
+                                    align_across(chunk.y, chunk.height, length, cross_alignment);
+                                chunk.y = y;
+                                chunk.height = height;
+                            }
+                        }
+                        Direction::Vertical => {
+                            chunk.y += offset;
+                            if let (Some(cross_alignment), Some(length)) =
+                                (layout.cross_alignment, cross_length)
+                            {
+                                let (x, width) =
+                                    align_across(chunk.x, chunk.width, length, cross_alignment);
+                                chunk.x = x;
+                                chunk.width = width;
+                            }
+                        }
+                    }
+                    // the spacing is taken from the start of every child after the first
+                    if i > 0 {
+                        match layout.direction {
+                            Direction::Horizontal => {
+                                let spacing = std::cmp::min(layout.spacing, chunk.width);
+                                chunk.x += spacing;
+                                chunk.width -= spacing;
+                            }
+                            Direction::Vertical => {
+                                let spacing = std::cmp::min(layout.spacing, chunk.height);
+                                chunk.y += spacing;
+                                chunk.height -= spacing;
+                            }
+                        }
+                    }
+                    path.push(i);
+                    self.draw_widget_node_tree(child, frame, chunk, path, event);
+                    path.pop();
                 }
             }
             TuiWidget::Paragraph(paragraph) => {
                 let text: Vec<Text> = paragraph.text.iter().map(|txt| Text::raw(txt)).collect();
-                let mut actual_paragraph: Paragraph<_, MSG> =
-                    Paragraph::new(text.iter()).area(area);
+                let style = over_theme(paragraph.style, self.text_style());
+                let mut actual_paragraph: Paragraph<_, MSG> = Paragraph::new(text.iter())
+                    .alignment(paragraph.alignment)
+                    .style(style)
+                    .area(area);
+                self.dispatch_pointer_events(&paragraph.events, area, event);
                 actual_paragraph.events = convert_events(paragraph.events);
 
                 //TODO: the area of widgets should be handled when converting from abstract Widget
                 if let Some(block) = &paragraph.block {
-                    let mut tui_block = itui::widgets::Block::default()
-                        .title_style(block.title_style)
-                        .borders(block.borders)
-                        .border_style(block.border_style)
-                        .area(area)
-                        .style(block.style);
-                    if let Some(title) = &block.title {
-                        tui_block = tui_block.title(&title);
-                    }
-
-                    if let Some(event) = event {
-                        let cb = tui_block.triggers_event(event);
-                        if let Some(cb) = cb {
-                            let msg = cb.emit(event.clone());
-                            self.app.borrow_mut().update(msg);
-                        }
-                    }
-
+                    let tui_block = self.to_tui_block(block, area, event);
                     actual_paragraph = actual_paragraph.block(tui_block);
                 }
                 if let Some(event) = event {
                     let cb = actual_paragraph.triggers_event(event);
                     if let Some(cb) = cb {
                         let msg = cb.emit(event.clone());
-                        self.app.borrow_mut().update(msg);
+                        self.dispatch(msg);
                     }
                 }
                 actual_paragraph.render(frame);
             }
-            TuiWidget::Button(mut button) => {
+            TuiWidget::Button(mut button, pointer_events) => {
                 button = button.area(area);
+                self.dispatch_pointer_events(&pointer_events, area, event);
 
                 if let Some(event) = event {
                     let cb = button.triggers_event(event);
                     if let Some(cb) = cb {
                         let msg = cb.emit(event.clone());
-                        self.app.borrow_mut().update(msg);
+                        self.dispatch(msg);
                     }
                 }
                 button.render(frame);
             }
+            TuiWidget::LineChart(chart) => {
+                let (min, max) = chart.bounds();
+                let points: Vec<Vec<(f64, f64)>> = chart
+                    .series
+                    .iter()
+                    .map(|series| {
+                        series
+                            .data
+                            .iter()
+                            .enumerate()
+                            .map(|(i, v)| (i as f64, *v))
+                            .collect()
+                    })
+                    .collect();
+                let datasets: Vec<Dataset> = chart
+                    .series
+                    .iter()
+                    .zip(points.iter())
+                    .map(|(series, data)| {
+                        Dataset::default()
+                            .name(&series.name)
+                            .marker(Marker::Braille)
+                            .style(Style::default().fg(to_tui_color(series.color)))
+                            .data(data)
+                    })
+                    .collect();
+                let x_max = chart.point_count().saturating_sub(1) as f64;
+                let mut tui_chart = Chart::default()
+                    .x_axis(Axis::default().bounds([0.0, x_max]).labels(&chart.x_labels))
+                    .y_axis(Axis::default().bounds([min, max]).labels(&chart.y_labels))
+                    .datasets(&datasets)
+                    .area(area);
+                tui_chart.render(frame);
+            }
+            TuiWidget::BarChart(chart) => {
+                // the bars of each series are placed next to each other,
+                // the bar chart can only be drawn in a single color
+                let bounds = chart.bounds();
+                let normalized: Vec<Vec<f64>> = chart
+                    .series
+                    .iter()
+                    .map(|series| series.normalized(bounds))
+                    .collect();
+                let mut data: Vec<(&str, u64)> = vec![];
+                for i in 0..chart.point_count() {
+                    let label = chart.x_labels.get(i).map(|l| l.as_str()).unwrap_or("");
+                    for values in normalized.iter() {
+                        let value = values.get(i).cloned().unwrap_or(0.0);
+                        data.push((label, (value * 100.0).round() as u64));
+                    }
+                }
+                let color = chart
+                    .series
+                    .first()
+                    .map(|series| to_tui_color(series.color))
+                    .unwrap_or(Color::Reset);
+                let mut tui_chart = BarChart::default()
+                    .data(&data)
+                    .max(100)
+                    .bar_width(3)
+                    .style(Style::default().fg(color))
+                    .area(area);
+                tui_chart.render(frame);
+            }
+            TuiWidget::Sparkline(series) => {
+                let data: Vec<u64> = series
+                    .normalized(series.bounds())
+                    .iter()
+                    .map(|v| (v * 100.0).round() as u64)
+                    .collect();
+                let mut sparkline = Sparkline::default()
+                    .data(&data)
+                    .max(100)
+                    .style(Style::default().fg(to_tui_color(series.color)))
+                    .area(area);
+                sparkline.render(frame);
+            }
+            TuiWidget::Sized(widget, sizing) => {
+                let inset = sizing.margin.unwrap_or(0.0) + sizing.padding.unwrap_or(0.0);
+                let inner_area = inset_area(area, inset);
+                self.draw_widget_node_tree(*widget, frame, inner_area, path, event);
+            }
+            // a hidden widget is given no space and is not drawn
+            TuiWidget::Hidden(_) => (),
+            TuiWidget::Disabled(widget) => {
+                // no event is passed down, so neither this widget
+                // nor its children trigger their callbacks
+                self.draw_widget_node_tree(*widget, frame, area, path, &None);
+            }
+            TuiWidget::Separator(direction) => {
+                let line = match direction {
+                    Direction::Horizontal => "─".repeat(area.width as usize),
+                    Direction::Vertical => vec!["│"; area.height as usize].join("\n"),
+                };
+                let text = [Text::raw(line)];
+                let mut separator: Paragraph<_, MSG> = Paragraph::new(text.iter())
+                    .style(self.border_style())
+                    .area(area);
+                separator.render(frame);
+            }
+        }
+    }
+
+    /// create the itui block at this area,
+    /// dispatching the event if it is triggered in the block
+    fn to_tui_block<'b>(
+        &self,
+        block: &'b nodes::Block<MSG>,
+        area: Rect,
+        event: &Option<Event>,
+    ) -> itui::widgets::Block<'b, MSG>
+    where
+        MSG: 'static,
+    {
+        let border_style = over_theme(block.border_style, self.border_style());
+        let mut tui_block = itui::widgets::Block::default()
+            .title_style(block.title_style)
+            .borders(block.borders)
+            .border_style(border_style)
+            .area(area)
+            .style(over_theme(block.style, self.text_style()));
+        if let Some(title) = &block.title {
+            tui_block = tui_block.title(&title);
+        }
+        self.dispatch_pointer_events(&block.events, area, event);
+
+        if let Some(event) = event {
+            let cb = tui_block.triggers_event(event);
+            if let Some(cb) = cb {
+                let msg = cb.emit(event.clone());
+                self.dispatch(msg);
+            }
+        }
+        tui_block
+    }
+
+    /// move the focus as requested by the app, to the autofocus widget
+    /// when none has the focus, and along the focus ring with Tab and Shift-Tab.
+    /// Space and Enter activate the focused widget.
+    /// Returns whether the event is a Tab which is used up for the traversal
+    fn update_focus(&self, view: &Node<MSG>, event: &Option<Event>) -> bool {
+        let focus_request = self.app.borrow().focus();
+        for msg in self.focus.update(view, focus_request) {
+            self.dispatch(msg);
+        }
+        match event {
+            Some(Event::KeyEvent(key_event)) => {
+                let (messages, is_tab) = self.focus.handle_key(view, key_event);
+                for msg in messages {
+                    self.dispatch(msg);
+                }
+                is_tab
+            }
+            _ => false,
+        }
+    }
+
+    /// dispatch the double click, wheel, move, enter and leave events of the widget
+    /// in this area, with the coordinate relative to the top left of the area
+    fn dispatch_pointer_events(
+        &self,
+        events: &[Attribute<MSG>],
+        area: Rect,
+        event: &Option<Event>,
+    ) where
+        MSG: 'static,
+    {
+        let mouse_event = match event {
+            Some(Event::MouseEvent(mouse_event)) => mouse_event,
+            _ => return,
+        };
+        let coordinate = &mouse_event.coordinate;
+        let pointer = (coordinate.x as u16, coordinate.y as u16);
+        let is_inside = area_contains(area, pointer);
+        let was_inside = self
+            .last_pointer
+            .get()
+            .map(|last_pointer| area_contains(area, last_pointer))
+            .unwrap_or(false);
+        for att in events {
+            let event_type = match (&att.name, mouse_event.r#type) {
+                (AttribKey::DoubleClickEvent, "dblclick") if is_inside => "dblclick",
+                (AttribKey::WheelEvent, "wheel") if is_inside => "wheel",
+                (AttribKey::MouseMoveEvent, "mousemove") if is_inside => "mousemove",
+                (AttribKey::MouseEnterEvent, _) if is_inside && !was_inside => "mouseenter",
+                (AttribKey::MouseLeaveEvent, _) if !is_inside && was_inside => "mouseleave",
+                _ => continue,
+            };
+            if let Some(cb) = att.get_callback() {
+                let relative_event = MouseEvent {
+                    r#type: event_type,
+                    // the termion coordinates start at 1
+                    coordinate: Coordinate::new(
+                        coordinate.x - area.x as i32 - 1,
+                        coordinate.y - area.y as i32 - 1,
+                    ),
+                    ..mouse_event.clone()
+                };
+                let msg = cb.emit(relative_event);
+                self.dispatch(msg);
+            }
+        }
+    }
+
+    /// the foreground and background colors of the theme,
+    /// the colors of the terminal are kept when there is no theme
+    fn text_style(&self) -> Style {
+        match &*self.theme.borrow() {
+            Some(theme) => Style::default()
+                .fg(to_tui_color(theme.palette.foreground))
+                .bg(to_tui_color(theme.palette.background)),
+            None => Style::default(),
+        }
+    }
+
+    fn border_style(&self) -> Style {
+        match &*self.theme.borrow() {
+            Some(theme) => Style::default()
+                .fg(to_tui_color(theme.palette.border))
+                .bg(to_tui_color(theme.palette.background)),
+            None => Style::default(),
         }
     }
 }
@@ -166,24 +662,93 @@ where
     events
         .into_iter()
         .filter_map(|att| {
-            let att_name = att.name.to_static_str();
-            if let Some(cb) = att.take_callback() {
-                Some(sauron_vdom::Attribute::from_callback(att_name, cb))
-            } else {
-                None
-            }
+            // only the click and input events are converted, the key and focus events
+            // are delivered from the view, see `draw_ui`, the pointer events
+            // by `dispatch_pointer_events`, and the rest are skipped
+            let name = att.name.to_static_str()?;
+            att.take_callback()
+                .map(|cb| sauron_vdom::Attribute::from_callback(name, cb))
         })
         .collect()
 }
 
+/// the pointer events which itui does not dispatch
+fn is_pointer_event(key: &AttribKey) -> bool {
+    matches!(
+        key,
+        AttribKey::DoubleClickEvent
+            | AttribKey::MouseEnterEvent
+            | AttribKey::MouseLeaveEvent
+            | AttribKey::MouseMoveEvent
+            | AttribKey::WheelEvent
+    )
+}
+
+/// whether the cell, in the 1-based coordinates of termion, is in the area
+fn area_contains(area: Rect, (x, y): (u16, u16)) -> bool {
+    x > area.x && x <= area.x + area.width && y > area.y && y <= area.y + area.height
+}
+
+/// shrink the area on all sides by the pixels converted into cells
+fn inset_area(area: Rect, pixels: f64) -> Rect {
+    let columns = std::cmp::min((pixels / PIXELS_PER_COLUMN).round() as u16, area.width / 2);
+    let rows = std::cmp::min((pixels / PIXELS_PER_ROW).round() as u16, area.height / 2);
+    Rect {
+        x: area.x + columns,
+        y: area.y + rows,
+        width: area.width - columns * 2,
+        height: area.height - rows * 2,
+    }
+}
+
+/// the start and the length of a child which has a fixed length across its layout
+fn align_across(
+    start: u16,
+    available: u16,
+    length: u16,
+    alignment: property::Alignment,
+) -> (u16, u16) {
+    let length = std::cmp::min(length, available);
+    let free = available - length;
+    match alignment {
+        property::Alignment::Start => (start, length),
+        property::Alignment::Center => (start + free / 2, length),
+        property::Alignment::End => (start + free, length),
+        property::Alignment::Stretch | property::Alignment::SpaceBetween => (start, available),
+    }
+}
+
+fn to_tui_color(color: property::Color) -> Color {
+    Color::Rgb(color.red, color.green, color.blue)
+}
+
+/// the colors set in the style of the node take precedence over the theme
+fn over_theme(style: Style, theme_style: Style) -> Style {
+    let pick = |color, theme_color| {
+        if color == Color::Reset {
+            theme_color
+        } else {
+            color
+        }
+    };
+    Style {
+        fg: pick(style.fg, theme_style.fg),
+        bg: pick(style.bg, theme_style.bg),
+        modifier: style.modifier,
+    }
+}
+
 impl AttribKey {
-    fn to_static_str(&self) -> &'static str {
+    /// the name of the attribute in itui,
+    /// the attributes it has no use for are skipped
+    fn to_static_str(&self) -> Option<&'static str> {
         match self {
-            AttribKey::ClickEvent => "click",
-            AttribKey::InputEvent => "input",
-            AttribKey::Value => "value",
-            AttribKey::Label => "label",
-            _ => panic!("not yet implemented for {}", self),
+            AttribKey::ClickEvent => Some("click"),
+            AttribKey::InputEvent => Some("input"),
+            AttribKey::Value => Some("value"),
+            AttribKey::Label => Some("label"),
+            AttribKey::Icon => Some("icon"),
+            _ => None,
         }
     }
 }
@@ -195,13 +760,30 @@ where
 {
     fn init(app: APP) -> Rc<Self> {
         let terminal = setup_terminal().expect("unable to setup terminal");
+        let theme = app.theme();
+        let subscriptions = app.subscriptions();
+        let events = Events::new();
         let tui_backend = TuiBackend {
             terminal: Rc::new(RefCell::new(terminal)),
             app: Rc::new(RefCell::new(app)),
+            theme: RefCell::new(theme),
+            last_pointer: Cell::new(None),
+            areas: RefCell::new(vec![]),
+            focus: Focus::new(),
+            screen_reader: screen_reader::is_enabled(),
+            live_regions: LiveRegions::default(),
+            commands: RefCell::new(vec![]),
+            quit: Cell::new(false),
+            subscriptions: RefCell::new(subscriptions),
+            timers: RefCell::new(Timers::new()),
+            executor: RefCell::new(Executor::new(events.waker())),
+            events,
             _phantom_msg: PhantomData,
         };
 
         let backend = Rc::new(tui_backend);
+        let init_cmd = backend.app.borrow_mut().init();
+        backend.after_update(init_cmd);
         backend.start_draw_loop();
         backend
     }
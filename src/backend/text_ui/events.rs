@@ -10,9 +10,12 @@ use std::{
     marker::PhantomData,
     ops::Deref,
     rc::Rc,
-    sync::mpsc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use termion::{
     event::{
@@ -24,10 +27,24 @@ use termion::{
     screen::AlternateScreen,
 };
 
+/// what the draw loop is woken up by
+pub enum Input {
+    Event(Event),
+    /// sent every tick of the config, while the draw loop is ticking
+    Tick,
+    /// the terminal is resized to this width and height in cells
+    Resize(u16, u16),
+    /// a future performed by a command is woken up
+    Wake,
+}
+
 pub struct Events {
-    rx: mpsc::Receiver<Event>,
+    rx: mpsc::Receiver<Input>,
+    tx: mpsc::Sender<Input>,
     input_handle: thread::JoinHandle<()>,
     tick_handle: thread::JoinHandle<()>,
+    /// whether the ticks are sent, they are only needed by the timers and the futures
+    ticking: Arc<AtomicBool>,
 }
 
 pub struct ItuiEvent(TermEvent);
@@ -48,48 +65,126 @@ impl Events {
             let tx = tx.clone();
             thread::spawn(move || {
                 let stdin = io::stdin();
+                let mut last_click: Option<(Instant, u16, u16)> = None;
                 for evt in stdin.events() {
                     let evt = evt.unwrap();
                     let itui_event = ItuiEvent(evt);
                     if itui_event.is_break() {
                         println!("control break..");
                     }
+                    let double_click = itui_event.double_click(&mut last_click);
                     let event: Event = itui_event.into();
-                    tx.send(event);
+                    tx.send(Input::Event(event));
+                    // the terminal only reports the presses,
+                    // so a double click follows the second click
+                    if let Some(double_click) = double_click {
+                        tx.send(Input::Event(double_click));
+                    }
                 }
             })
         };
+        let ticking = Arc::new(AtomicBool::new(false));
         let tick_handle = {
             let tx = tx.clone();
+            let ticking = Arc::clone(&ticking);
             thread::spawn(move || {
-                let tx = tx.clone();
+                // termion has no resize event, so the size is compared on every tick
+                let mut size = termion::terminal_size().ok();
                 loop {
-                    //tx.send(Event::Tick).unwrap();
                     thread::sleep(config.tick_rate);
+                    let new_size = termion::terminal_size().ok();
+                    if new_size != size {
+                        size = new_size;
+                        if let Some((width, height)) = size {
+                            if tx.send(Input::Resize(width, height)).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    if !ticking.load(Ordering::SeqCst) {
+                        continue;
+                    }
+                    // the receiver is gone once the draw loop ended
+                    if tx.send(Input::Tick).is_err() {
+                        return;
+                    }
                 }
             })
         };
         Events {
             rx,
+            tx,
             input_handle,
             tick_handle,
+            ticking,
         }
     }
 
-    pub fn next(&self) -> Result<Event, mpsc::RecvError> {
+    /// start or stop sending the ticks, the resizes are checked either way
+    pub fn set_ticking(&self, ticking: bool) {
+        self.ticking.store(ticking, Ordering::SeqCst);
+    }
+
+    pub fn next(&self) -> Result<Input, mpsc::RecvError> {
         self.rx.recv()
     }
+
+    /// wakes the draw loop up to poll the futures, from any thread
+    pub fn waker(&self) -> impl Fn() + Send + Sync {
+        let tx = Mutex::new(self.tx.clone());
+        move || {
+            if let Ok(tx) = tx.lock() {
+                let _ = tx.send(Input::Wake);
+            }
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Config {
         Config {
-            tick_rate: Duration::from_millis(250),
+            // the animation frames of the subscriptions are drawn on the ticks
+            tick_rate: Duration::from_millis(50),
         }
     }
 }
 
+/// the longest time between two clicks of a double click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
 impl ItuiEvent {
+    /// a double click event when this is a left click in the same cell
+    /// shortly after the last click, which is updated here
+    fn double_click(&self, last_click: &mut Option<(Instant, u16, u16)>) -> Option<Event> {
+        if let TermEvent::Mouse(term_event::MouseEvent::Press(
+            term_event::MouseButton::Left,
+            x,
+            y,
+        )) = self.deref()
+        {
+            let now = Instant::now();
+            let is_double = match last_click {
+                Some((at, last_x, last_y)) => {
+                    now.duration_since(*at) < DOUBLE_CLICK_INTERVAL
+                        && (*last_x, *last_y) == (*x, *y)
+                }
+                None => false,
+            };
+            if is_double {
+                *last_click = None;
+                let mouse_event = MouseEvent {
+                    r#type: "dblclick",
+                    coordinate: Coordinate::new(*x as i32, *y as i32),
+                    buttons: MouseButton::Left,
+                    ..Default::default()
+                };
+                return Some(Event::from(mouse_event));
+            }
+            *last_click = Some((now, *x, *y));
+        }
+        None
+    }
+
     fn is_break(&self) -> bool {
         match self.deref() {
             TermEvent::Key(ke) => match ke {
@@ -111,21 +206,37 @@ impl Deref for ItuiEvent {
 impl Into<Event> for ItuiEvent {
     fn into(self) -> Event {
         match self.deref() {
-            TermEvent::Key(ke) => match ke {
-                TermKey::Char(ch) => {
-                    let key_event = KeyEvent::new(ch.to_string());
-                    Event::from(key_event)
-                }
-                TermKey::Ctrl(ch) => {
-                    let key_event = KeyEvent {
-                        key: ch.to_string(),
-                        modifier: Modifier::ctrl(),
-                        ..Default::default()
-                    };
-                    Event::from(key_event)
-                }
-                _ => panic!("unable to decode char from key event :{:#?}", ke),
-            },
+            TermEvent::Key(ke) => {
+                // the keys are named the same as the key of the html keyboard event
+                let (key, modifier) = match ke {
+                    TermKey::Char('\n') => ("Enter".to_string(), Modifier::default()),
+                    TermKey::Char('\t') => ("Tab".to_string(), Modifier::default()),
+                    TermKey::Char(ch) => (ch.to_string(), Modifier::default()),
+                    TermKey::Ctrl(ch) => (ch.to_string(), Modifier::ctrl()),
+                    TermKey::Alt(ch) => (
+                        ch.to_string(),
+                        Modifier {
+                            alt_key: true,
+                            ..Default::default()
+                        },
+                    ),
+                    TermKey::BackTab => (
+                        "Tab".to_string(),
+                        Modifier {
+                            shift_key: true,
+                            ..Default::default()
+                        },
+                    ),
+                    TermKey::F(n) => (format!("F{}", n), Modifier::default()),
+                    _ => (named_key(ke).to_string(), Modifier::default()),
+                };
+                let key_event = KeyEvent {
+                    key,
+                    modifier,
+                    ..Default::default()
+                };
+                Event::from(key_event)
+            }
             TermEvent::Mouse(me) => match me {
                 term_event::MouseEvent::Press(btn, x, y) => {
                     let mb = match btn {
@@ -135,8 +246,12 @@ impl Into<Event> for ItuiEvent {
                         term_event::MouseButton::WheelUp => MouseButton::WheelUp,
                         term_event::MouseButton::WheelDown => MouseButton::WheelDown,
                     };
+                    let event_type = match mb {
+                        MouseButton::WheelUp | MouseButton::WheelDown => "wheel",
+                        _ => "click",
+                    };
                     let mouse_event = MouseEvent {
-                        r#type: "click",
+                        r#type: event_type,
                         coordinate: Coordinate::new(*x as i32, *y as i32),
                         buttons: mb,
                         ..Default::default()
@@ -153,7 +268,8 @@ impl Into<Event> for ItuiEvent {
                 }
                 term_event::MouseEvent::Hold(x, y) => {
                     let mouse_event = MouseEvent {
-                        r#type: "hold", //drag?
+                        // the pointer moving while a button is held
+                        r#type: "mousemove",
                         coordinate: Coordinate::new(*x as i32, *y as i32),
                         ..Default::default()
                     };
@@ -164,3 +280,22 @@ impl Into<Event> for ItuiEvent {
         }
     }
 }
+
+/// the name of the keys which are not characters
+fn named_key(key: &TermKey) -> &'static str {
+    match key {
+        TermKey::Backspace => "Backspace",
+        TermKey::Left => "ArrowLeft",
+        TermKey::Right => "ArrowRight",
+        TermKey::Up => "ArrowUp",
+        TermKey::Down => "ArrowDown",
+        TermKey::Home => "Home",
+        TermKey::End => "End",
+        TermKey::PageUp => "PageUp",
+        TermKey::PageDown => "PageDown",
+        TermKey::Delete => "Delete",
+        TermKey::Insert => "Insert",
+        TermKey::Esc => "Escape",
+        _ => "Unidentified",
+    }
+}
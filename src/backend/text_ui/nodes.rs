@@ -1,18 +1,44 @@
-use super::TuiBackend;
-use crate::{widget::attribute::find_value, AttribKey, Attribute, Widget};
+use super::{to_tui_color, TuiBackend};
+use crate::{
+    backend::{PIXELS_PER_COLUMN, PIXELS_PER_ROW},
+    widget::{
+        attribute::find_value,
+        chart::{Chart, Series},
+        icon::Icon,
+        property::{self, BorderStyle, Orientation},
+        sizing::{Length, Sizing},
+    },
+    AttribKey, Attribute, Widget,
+};
 use itui::{
     layout::{Alignment, Constraint, Corner, Direction},
-    style::Style,
+    style::{Modifier, Style},
     widgets::{Borders, Button},
 };
-use sauron_vdom::{self, Callback, Event};
+use sauron_vdom::{self, event::InputEvent, Callback, Event};
 use std::rc::Rc;
 
 #[derive(Clone)]
 pub enum TuiWidget<MSG> {
     Layout(Layout<MSG>),
     Paragraph(Paragraph<MSG>),
-    Button(Button<MSG>),
+    /// the pointer events of the button other than the click
+    /// are dispatched by the backend
+    Button(Button<MSG>, Vec<Attribute<MSG>>),
+    /// a line drawn across the area alloted by the parent layout
+    Separator(Direction),
+    LineChart(Chart),
+    BarChart(Chart),
+    Sparkline(Series),
+    /// a widget with sizing attributes,
+    /// drawn inset by its margin and padding
+    Sized(Box<TuiWidget<MSG>>, Sizing),
+    /// a dimmed widget which, along with its children,
+    /// is skipped when dispatching the events
+    Disabled(Box<TuiWidget<MSG>>),
+    /// a widget which takes no space and is not drawn,
+    /// but is kept in the tree
+    Hidden(Box<TuiWidget<MSG>>),
 }
 #[derive(Clone)]
 pub struct Paragraph<MSG> {
@@ -28,6 +54,8 @@ pub struct Paragraph<MSG> {
     pub scroll: u16,
     /// Aligenment of the text
     pub alignment: Alignment,
+    /// preferred constraint when parent is a layout
+    pub preferred_constraint: Option<Constraint>,
     /// events attached to this block
     /// TODO: This doesn't need to hold the events, just store the event to the actual paragraph
     pub events: Vec<Attribute<MSG>>,
@@ -35,9 +63,21 @@ pub struct Paragraph<MSG> {
 
 #[derive(Clone)]
 pub struct Layout<MSG> {
+    /// A block to wrap the children in
+    pub block: Option<Block<MSG>>,
     pub direction: Direction,
     pub margin: u16,
+    /// cells between each of the children
+    pub spacing: u16,
     pub constraints: Vec<Constraint>,
+    /// how the children are placed along the direction
+    /// when they do not fill the layout
+    pub alignment: Option<property::Alignment>,
+    /// how the children with a fixed length across the direction are placed
+    pub cross_alignment: Option<property::Alignment>,
+    /// the children added after the first one are not shown,
+    /// used in a collapsed expander
+    pub collapsed: bool,
     pub children: Vec<TuiWidget<MSG>>,
 }
 
@@ -73,29 +113,158 @@ impl<MSG> TuiWidget<MSG> {
     fn as_layout(&mut self) -> Option<&mut Layout<MSG>> {
         match self {
             TuiWidget::Layout(layout) => Some(layout),
+            TuiWidget::Sized(widget, _) => widget.as_layout(),
+            TuiWidget::Disabled(widget) | TuiWidget::Hidden(widget) => widget.as_layout(),
             _ => None,
         }
     }
+
+    /// the colors of a paragraph or a framed layout,
+    /// the colors which are not set are taken from the theme
+    fn set_colors(&mut self, fg: Option<property::Color>, bg: Option<property::Color>) {
+        let style = match self {
+            TuiWidget::Paragraph(paragraph) => &mut paragraph.style,
+            TuiWidget::Layout(Layout {
+                block: Some(block), ..
+            }) => &mut block.style,
+            TuiWidget::Sized(widget, _) => return widget.set_colors(fg, bg),
+            TuiWidget::Disabled(widget) | TuiWidget::Hidden(widget) => {
+                return widget.set_colors(fg, bg)
+            }
+            _ => return,
+        };
+        if let Some(fg) = fg {
+            *style = style.fg(to_tui_color(fg));
+        }
+        if let Some(bg) = bg {
+            *style = style.bg(to_tui_color(bg));
+        }
+    }
+
+    /// the constraint this widget needs in a parent layout of this direction,
+    /// widgets without one share the remaining space according to their flex grow
+    pub fn preferred_constraint(&self, direction: &Direction) -> Option<Constraint> {
+        match self {
+            // the line is one cell thick across its own direction
+            TuiWidget::Separator(line) if line != direction => Some(Constraint::Length(1)),
+            TuiWidget::Separator(_) => None,
+            TuiWidget::Paragraph(paragraph) => paragraph.preferred_constraint,
+            TuiWidget::Sized(widget, sizing) => {
+                let (length, min, max, cell_size) = match direction {
+                    Direction::Horizontal => (
+                        sizing.width,
+                        sizing.min_width,
+                        sizing.max_width,
+                        PIXELS_PER_COLUMN,
+                    ),
+                    Direction::Vertical => (
+                        sizing.height,
+                        sizing.min_height,
+                        sizing.max_height,
+                        PIXELS_PER_ROW,
+                    ),
+                };
+                let to_cells = |px: f64| (px / cell_size).round() as u16;
+                match (length, min, max) {
+                    (Some(Length::Px(px)), _, _) => Some(Constraint::Length(to_cells(px))),
+                    (Some(Length::Percent(percent)), _, _) => {
+                        Some(Constraint::Percentage(percent as u16))
+                    }
+                    (None, Some(Length::Px(px)), _) => Some(Constraint::Min(to_cells(px))),
+                    (None, None, Some(Length::Px(px))) => Some(Constraint::Max(to_cells(px))),
+                    _ => widget.preferred_constraint(direction),
+                }
+            }
+            TuiWidget::Disabled(widget) => widget.preferred_constraint(direction),
+            TuiWidget::Hidden(_) => Some(Constraint::Length(0)),
+            _ => None,
+        }
+    }
+
+    /// the share of the remaining space in the parent layout
+    fn flex_grow(&self) -> f64 {
+        match self {
+            TuiWidget::Sized(_, sizing) => sizing.flex_grow.unwrap_or(1.0),
+            TuiWidget::Disabled(widget) => widget.flex_grow(),
+            TuiWidget::Hidden(_) => 0.0,
+            _ => 1.0,
+        }
+    }
+
+    /// dim the text and borders of this widget and its children
+    fn dim(&mut self) {
+        match self {
+            TuiWidget::Paragraph(paragraph) => {
+                paragraph.style = paragraph.style.modifier(Modifier::DIM);
+                if let Some(block) = &mut paragraph.block {
+                    block.dim();
+                }
+            }
+            TuiWidget::Layout(layout) => {
+                if let Some(block) = &mut layout.block {
+                    block.dim();
+                }
+                for child in layout.children.iter_mut() {
+                    child.dim();
+                }
+            }
+            TuiWidget::Sized(widget, _)
+            | TuiWidget::Disabled(widget)
+            | TuiWidget::Hidden(widget) => widget.dim(),
+            _ => (),
+        }
+    }
+
+    /// show the focused widget in reverse video,
+    /// only the header of an expander is highlighted
+    fn highlight(&mut self) {
+        match self {
+            TuiWidget::Paragraph(paragraph) => {
+                paragraph.style = paragraph.style.modifier(Modifier::REVERSED);
+            }
+            TuiWidget::Layout(layout) => {
+                if let Some(header) = layout.children.first_mut() {
+                    header.highlight();
+                }
+            }
+            TuiWidget::Sized(widget, _)
+            | TuiWidget::Disabled(widget)
+            | TuiWidget::Hidden(widget) => widget.highlight(),
+            _ => (),
+        }
+    }
 }
 
 impl<MSG> Layout<MSG> {
+    /// the constraints are computed from the children
+    /// unless they were given when the layout was made
     fn add_children(&mut self, children: Vec<TuiWidget<MSG>>) {
-        for child in children {
-            self.children.push(child);
+        // a collapsed expander only shows its header
+        if !self.collapsed {
+            for child in children {
+                self.children.push(child);
+            }
         }
         let child_count = self.children.len();
-        if self.constraints.is_empty() {
-            if child_count > 0 {
-                //TODO: get the sizes of each of the children
-                // and add set each independent reference
-                let alloted = 100 / child_count as u16;
-                let new_constraints = self
-                    .children
-                    .iter()
-                    .map(|child| Constraint::Percentage(alloted))
-                    .collect();
-                self.constraints = new_constraints;
-            }
+        if self.constraints.is_empty() && child_count > 0 {
+            let direction = &self.direction;
+            let total_grow: f64 = self
+                .children
+                .iter()
+                .filter(|child| child.preferred_constraint(direction).is_none())
+                .map(|child| child.flex_grow())
+                .sum();
+            let new_constraints = self
+                .children
+                .iter()
+                .map(|child| {
+                    child.preferred_constraint(direction).unwrap_or_else(|| {
+                        let alloted = 100.0 * child.flex_grow() / total_grow.max(1.0);
+                        Constraint::Percentage(alloted as u16)
+                    })
+                })
+                .collect();
+            self.constraints = new_constraints;
         }
     }
 }
@@ -103,9 +272,14 @@ impl<MSG> Layout<MSG> {
 impl<MSG> Default for Layout<MSG> {
     fn default() -> Self {
         Layout {
+            block: None,
             direction: Direction::Vertical,
             margin: 0,
+            spacing: 0,
             constraints: Vec::new(),
+            alignment: None,
+            cross_alignment: None,
+            collapsed: false,
             children: vec![],
         }
     }
@@ -119,11 +293,20 @@ impl<MSG> Default for Paragraph<MSG> {
             text: vec![],
             scroll: 0,
             alignment: Alignment::Left,
+            preferred_constraint: None,
             events: vec![],
         }
     }
 }
 
+impl<MSG> Block<MSG> {
+    fn dim(&mut self) {
+        self.style = self.style.modifier(Modifier::DIM);
+        self.title_style = self.title_style.modifier(Modifier::DIM);
+        self.border_style = self.border_style.modifier(Modifier::DIM);
+    }
+}
+
 impl<MSG> Default for Block<MSG> {
     fn default() -> Self {
         Block {
@@ -146,10 +329,64 @@ fn layout<MSG>(
     let mut layout = Layout::default();
 
     layout.direction = direction;
+    layout.constraints = constraints;
     layout.add_children(children);
     TuiWidget::Layout(layout)
 }
 
+/// a vertical layout wrapped in a titled block,
+/// the margin leaves room for the borders of the block
+fn framed_layout<MSG>(title: Option<String>, borders: Borders) -> TuiWidget<MSG> {
+    let mut block = Block::default();
+    block.title = title;
+    block.borders = borders;
+
+    let mut layout = Layout::default();
+    if borders != Borders::NONE {
+        layout.margin = 1;
+    }
+    layout.block = Some(block);
+    TuiWidget::Layout(layout)
+}
+
+/// clicking on the widget emits the toggle event with its new state
+fn toggle_events<MSG>(attrs: Vec<Attribute<MSG>>, new_state: bool) -> Vec<Attribute<MSG>>
+where
+    MSG: 'static,
+{
+    attrs
+        .into_iter()
+        .filter_map(|att| match att.name {
+            AttribKey::ToggleEvent => att.take_callback().map(|cb| {
+                let toggle_cb =
+                    Callback::from(move |_: Event| cb.emit(InputEvent::new(new_state.to_string())));
+                Attribute::from_callback(AttribKey::ClickEvent, toggle_cb)
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// a vertical layout with a one line header which toggles
+/// the visibility of the children added after it
+fn expander_layout<MSG>(
+    events: Vec<Attribute<MSG>>,
+    header_text: &str,
+    expanded: bool,
+) -> TuiWidget<MSG> {
+    let arrow = if expanded { "▾" } else { "▸" };
+    let mut header = Paragraph::default();
+    header.text = vec![format!("{} {}", arrow, header_text)];
+    header.events = events;
+    header.preferred_constraint = Some(Constraint::Length(1));
+
+    let mut layout = Layout::default();
+    // the constraints are computed when the children are added after the header
+    layout.children.push(TuiWidget::Paragraph(header));
+    layout.collapsed = !expanded;
+    TuiWidget::Layout(layout)
+}
+
 fn paragraph<MSG>(
     events: Vec<Attribute<MSG>>,
     block: Option<Block<MSG>>,
@@ -166,8 +403,11 @@ fn button<MSG>(events: Vec<Attribute<MSG>>, text: &str) -> TuiWidget<MSG>
 where
     MSG: 'static,
 {
+    let (pointer_events, events) = events
+        .into_iter()
+        .partition(|att| super::is_pointer_event(&att.name));
     let button = Button::new(super::convert_events(events), text);
-    TuiWidget::Button(button)
+    TuiWidget::Button(button, pointer_events)
 }
 
 fn plain_block<MSG>(events: Vec<Attribute<MSG>>) -> Block<MSG> {
@@ -181,23 +421,143 @@ where
     MSG: 'static,
 {
     match widget {
-        Widget::Vbox => layout(Direction::Vertical, vec![], vec![]),
-        Widget::Hbox => layout(Direction::Horizontal, vec![], vec![]),
+        Widget::Vbox | Widget::Hbox => {
+            let direction = match widget {
+                Widget::Vbox => Direction::Vertical,
+                _ => Direction::Horizontal,
+            };
+            let alignment = |key| {
+                find_value(key, &attrs)
+                    .map(property::Alignment::from_value)
+                    .flatten()
+            };
+            let mut tui_layout = layout(direction, vec![], vec![]);
+            if let TuiWidget::Layout(tui_layout) = &mut tui_layout {
+                tui_layout.alignment = alignment(AttribKey::Alignment);
+                tui_layout.cross_alignment = alignment(AttribKey::CrossAlignment);
+            }
+            tui_layout
+        }
+        Widget::Frame => {
+            let title = find_value(AttribKey::Label, &attrs).map(|v| v.to_string());
+            let border_style = find_value(AttribKey::BorderStyle, &attrs)
+                .map(BorderStyle::from_value)
+                .flatten()
+                .unwrap_or_default();
+            let borders = match border_style {
+                BorderStyle::None => Borders::NONE,
+                _ => Borders::ALL,
+            };
+            framed_layout(title, borders)
+        }
+        Widget::Separator(Orientation::Horizontal) => TuiWidget::Separator(Direction::Horizontal),
+        Widget::Separator(Orientation::Vertical) => TuiWidget::Separator(Direction::Vertical),
+        Widget::Expander => {
+            let header = find_value(AttribKey::Label, &attrs)
+                .map(|v| v.to_string())
+                .unwrap_or(String::new());
+            let expanded = find_value(AttribKey::Expanded, &attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
+            expander_layout(toggle_events(attrs, !expanded), &header, expanded)
+        }
         Widget::Button => {
             let label = find_value(AttribKey::Label, &attrs)
                 .map(|v| v.to_string())
                 .unwrap_or(String::new());
-            button(attrs, &label)
+            let label = match find_value(AttribKey::Icon, &attrs)
+                .map(Icon::from_value)
+                .flatten()
+            {
+                Some(btn_icon) => format!("{} {}", btn_icon.glyph(), label),
+                None => label,
+            };
+            let flag = |key| {
+                find_value(key, &attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(false)
+            };
+            // a button can not be dimmed nor highlighted, so a bordered paragraph
+            // is shown instead, which is dimmed when disabled and reversed when focused
+            let mut block = plain_block(vec![]);
+            block.borders = Borders::ALL;
+            if flag(AttribKey::Disabled) {
+                paragraph(vec![], Some(block), vec![label])
+            } else if flag(AttribKey::Focused) {
+                paragraph(attrs, Some(block), vec![label])
+            } else {
+                button(attrs, &label)
+            }
+        }
+        Widget::LineChart(chart) => TuiWidget::LineChart(chart),
+        Widget::BarChart(chart) => TuiWidget::BarChart(chart),
+        Widget::Sparkline(series) => TuiWidget::Sparkline(series),
+        Widget::Icon(icon, _size) => paragraph(attrs, None, vec![icon.glyph().to_string()]),
+        Widget::Text(txt) => {
+            let alignment = find_value(AttribKey::Alignment, &attrs)
+                .map(property::Alignment::from_value)
+                .flatten();
+            let mut text_paragraph = paragraph(attrs, Some(plain_block(vec![])), vec![txt]);
+            if let (TuiWidget::Paragraph(text_paragraph), Some(alignment)) =
+                (&mut text_paragraph, alignment)
+            {
+                text_paragraph.alignment = match alignment {
+                    property::Alignment::Start => Alignment::Left,
+                    property::Alignment::Center => Alignment::Center,
+                    property::Alignment::End => Alignment::Right,
+                    // the terminal can not justify text, so it is left aligned
+                    property::Alignment::Stretch | property::Alignment::SpaceBetween => {
+                        Alignment::Left
+                    }
+                };
+            }
+            text_paragraph
         }
-        Widget::Text(txt) => paragraph(attrs, Some(plain_block(vec![])), vec![txt]),
         Widget::TextInput => {
             let value = find_value(AttribKey::Value, &attrs)
                 .map(|v| v.to_string())
                 .unwrap_or(String::new());
-            paragraph(attrs, Some(plain_block(vec![])), vec![value])
+            let placeholder = find_value(AttribKey::Placeholder, &attrs).map(|v| v.to_string());
+            match placeholder {
+                // the hint is dimmed so it is not mistaken for the value
+                Some(placeholder) if value.is_empty() => {
+                    let mut hint = paragraph(attrs, Some(plain_block(vec![])), vec![placeholder]);
+                    if let TuiWidget::Paragraph(hint) = &mut hint {
+                        hint.style = hint.style.modifier(Modifier::DIM);
+                    }
+                    hint
+                }
+                _ => paragraph(attrs, Some(plain_block(vec![])), vec![value]),
+            }
+        }
+        Widget::Checkbox | Widget::Radio => {
+            let label = find_value(AttribKey::Label, &attrs)
+                .map(|v| v.to_string())
+                .unwrap_or(String::new());
+            let checked = find_value(AttribKey::Value, &attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
+            let mark = match (widget == Widget::Checkbox, checked) {
+                (true, true) => "[x]",
+                (true, false) => "[ ]",
+                (false, true) => "(•)",
+                (false, false) => "( )",
+            };
+            // a radio stays checked when it is clicked again
+            let new_state = widget == Widget::Radio || !checked;
+            let mut toggle = paragraph(
+                toggle_events(attrs, new_state),
+                None,
+                vec![format!("{} {}", mark, label)],
+            );
+            if let TuiWidget::Paragraph(toggle) = &mut toggle {
+                toggle.preferred_constraint = Some(Constraint::Length(1));
+            }
+            toggle
         }
-        Widget::Checkbox => button(vec![], "X"),
-        Widget::Radio => button(vec![], "O"),
         Widget::Image(bytes) => button(vec![], "Image here soon..."),
     }
 }
@@ -206,7 +566,38 @@ pub fn convert_widget_node_tree_to_tui_widget<'a, MSG>(
 ) -> TuiWidget<MSG> {
     match widget_node {
         crate::Node::Element(element) => {
+            let sizing = Sizing::from_attrs(&element.attrs);
+            let color = |key| {
+                find_value(key, &element.attrs)
+                    .map(property::Color::from_value)
+                    .flatten()
+            };
+            let (fg, bg) = (color(AttribKey::Color), color(AttribKey::BackgroundColor));
+            let disabled = find_value(AttribKey::Disabled, &element.attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
+            let visible = find_value(AttribKey::Visible, &element.attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(true);
+            let focused = find_value(AttribKey::Focused, &element.attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false);
             let mut tui_node = widget_to_tui_node(element.tag, element.attrs);
+            tui_node.set_colors(fg, bg);
+            if sizing != Sizing::default() {
+                if let Some(spacing) = sizing.spacing {
+                    if let Some(layout) = tui_node.as_layout() {
+                        layout.spacing = match layout.direction {
+                            Direction::Horizontal => (spacing / PIXELS_PER_COLUMN).round() as u16,
+                            Direction::Vertical => (spacing / PIXELS_PER_ROW).round() as u16,
+                        };
+                    }
+                }
+                tui_node = TuiWidget::Sized(Box::new(tui_node), sizing);
+            }
             if let Some(mut layout) = tui_node.as_layout() {
                 let mut children = vec![];
                 for child in element.children {
@@ -215,6 +606,16 @@ pub fn convert_widget_node_tree_to_tui_widget<'a, MSG>(
                 }
                 layout.add_children(children);
             }
+            if focused {
+                tui_node.highlight();
+            }
+            if disabled {
+                tui_node.dim();
+                tui_node = TuiWidget::Disabled(Box::new(tui_node));
+            }
+            if !visible {
+                tui_node = TuiWidget::Hidden(Box::new(tui_node));
+            }
             tui_node
         }
         crate::Node::Text(txt) => paragraph(vec![], None, vec![txt.text]),
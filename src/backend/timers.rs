@@ -0,0 +1,82 @@
+//! The timers and animation frames of the subscriptions in the terminal backends,
+//! they are checked on every tick of the draw loop since the terminal has no timers.
+use crate::{sub::Source, Sub};
+use std::{collections::HashMap, time::Instant};
+
+pub struct Timers {
+    /// when the timers and animation frames last fired
+    last_fired: HashMap<Source, Instant>,
+}
+
+impl Timers {
+    pub fn new() -> Self {
+        Timers {
+            last_fired: HashMap::new(),
+        }
+    }
+
+    /// start the timers of the new subscriptions and stop those no longer subscribed to,
+    /// the resizes are not timed
+    pub fn sync<MSG>(&mut self, sub: &Sub<MSG>)
+    where
+        MSG: 'static,
+    {
+        let subscribed = sub.sources();
+        self.last_fired.retain(|source, _| subscribed.contains(source));
+        for source in subscribed {
+            if source != Source::Resize {
+                self.last_fired.entry(source).or_insert_with(Instant::now);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.last_fired.is_empty()
+    }
+
+    /// the messages of the timers which are due and of the animation frames
+    pub fn fire<MSG>(&mut self, sub: &Sub<MSG>) -> Vec<MSG>
+    where
+        MSG: 'static,
+    {
+        let now = Instant::now();
+        let mut messages = vec![];
+        for (source, last) in self.last_fired.iter_mut() {
+            let elapsed = now.duration_since(*last);
+            match source {
+                Source::Every(interval) if elapsed >= *interval => {
+                    messages.extend(sub.every_messages(*interval));
+                    *last = now;
+                }
+                Source::AnimationFrame => {
+                    let elapsed = elapsed.as_secs_f64() * 1000.0;
+                    messages.extend(sub.animation_frame_messages(elapsed));
+                    *last = now;
+                }
+                _ => (),
+            }
+        }
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn fire_the_due_timers() {
+        let sub = Sub::batch(vec![
+            Sub::every(Duration::from_secs(0), || "now"),
+            Sub::every(Duration::from_secs(3600), || "later"),
+            Sub::resize(|_, _| "resized"),
+        ]);
+        let mut timers = Timers::new();
+        timers.sync(&sub);
+        assert_eq!(timers.fire(&sub), vec!["now"]);
+        timers.sync(&Sub::<&str>::none());
+        assert!(timers.is_empty());
+        assert!(timers.fire(&sub).is_empty());
+    }
+}
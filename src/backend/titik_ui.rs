@@ -1,34 +1,77 @@
 use crate::{
-    widget::{attribute::find_value, Widget},
-    AttribKey, Attribute, Backend, Component, Node,
+    backend::{
+        executor::Executor,
+        focus::Focus,
+        mark_state,
+        screen_reader::{self, LiveRegions},
+        target_callback,
+        timers::Timers,
+        PIXELS_PER_COLUMN,
+        PIXELS_PER_ROW,
+    },
+    cmd::Command,
+    widget::{
+        attribute::{attr, find_callback, find_value},
+        icon::Icon,
+        property::{self, Alignment, Orientation},
+        sizing::{Length, Sizing},
+        Widget,
+    },
+    AttribKey, Attribute, Backend, Cmd, Component, Element, Node, Sub,
+};
+use sauron_vdom::{
+    event::{Coordinate, InputEvent, KeyEvent, Modifier, MouseButton, MouseEvent},
+    Dispatch,
 };
-use sauron_vdom::Dispatch;
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     fmt::Debug,
     io::{self, Stdout, Write},
     marker::PhantomData,
     rc::Rc,
     sync::mpsc,
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use titik::{
     crossterm,
     crossterm::{
-        event::{self, Event, KeyCode, KeyEvent},
-        terminal,
+        event::{
+            self, Event, KeyCode, KeyEvent as TermKeyEvent, KeyModifiers,
+            MouseButton as TermMouseButton, MouseEvent as TermMouseEvent,
+        },
+        style, terminal,
     },
     stretch::{
-        geometry::Size,
+        geometry::{Rect, Size},
         number::Number,
-        style::{Dimension, Style},
+        style::{AlignItems, Dimension, JustifyContent, Style},
     },
-    Box, Buffer, Button, Checkbox, Control, Image, Radio, TextInput,
+    Box, Buffer, Button, Checkbox, Control, Image, LayoutTree, Radio, TextInput,
 };
 
+/// how often the timers of the subscriptions and the futures are checked
+/// while waiting for an event
+const TICK_RATE: Duration = Duration::from_millis(50);
+
+/// the longest time between two clicks on the same cell which make a double click
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+
 pub struct TitikBackend<APP, MSG> {
     app: Rc<RefCell<APP>>,
+    /// the subscriptions of the app, read again after every update
+    subscriptions: RefCell<Sub<MSG>>,
+    timers: RefCell<Timers>,
+    /// the futures performed by the commands, polled on the ticks
+    executor: RefCell<Executor<MSG>>,
+    /// the widget which has the focus, moved with Tab and Shift-Tab
+    focus: Focus<MSG>,
+    /// the live regions of the view, their changes are announced by the screen reader
+    live_regions: LiveRegions,
+    /// the paths of child indices to the widgets under the pointer
+    hovered: RefCell<Vec<Vec<usize>>>,
+    /// the time and the cell of the last click, to tell a double click
+    last_click: Cell<Option<(Instant, u16, u16)>>,
     _phantom_msg: PhantomData<MSG>,
 }
 
@@ -44,55 +87,295 @@ where
 
     fn run<W: Write>(&self, w: &mut W) -> crossterm::Result<()> {
         titik::command::init(w);
-        let (t_width, t_height) = terminal::size()?;
-        let (width, height) = (t_width, t_height);
-
-        let vdom = self.app.borrow().view();
-        let mut control = Self::from_node_tree(vdom);
-        control.set_size(Some(width as f32), Some(height as f32));
-
-        let layout_tree = titik::compute_layout(
-            &mut control,
-            Size {
-                width: Number::Defined(width as f32),
-                height: Number::Defined(height as f32),
-            },
-        );
+        crossterm::execute!(w, event::EnableMouseCapture)?;
+        let (mut width, mut height) = terminal::size()?;
+        let init_cmd = self.app.borrow_mut().init();
+        if self.after_update(init_cmd) {
+            crossterm::execute!(w, event::DisableMouseCapture)?;
+            titik::command::finalize(w);
+            return Ok(());
+        }
+
         loop {
-            titik::command::reset_top(w)?;
-            let mut buf = Buffer::new(width as usize, height as usize);
-            control.draw(&mut buf, &layout_tree);
-            write!(w, "{}", buf);
+            let mut quit = false;
+            // the focus moves as requested by the app, and leaves the widgets which are gone
+            let focus_request = self.app.borrow().focus();
+            let view = self.app.borrow().view();
+            for msg in self.focus.update(&view, focus_request) {
+                quit |= self.update_app(msg);
+            }
+            // the controls are built again from the view, which changes after an update
+            let vdom = {
+                let app = self.app.borrow();
+                let mut view = app.view();
+                if let Some(path) = self.focus.focused_path(&view) {
+                    mark_state(&mut view, &path, AttribKey::Focused);
+                }
+                // the terminal reports the pointer moving only while a button is held,
+                // so the hovered widget is the innermost one under the last pointer event
+                if let Some(path) = self.hovered.borrow().last() {
+                    mark_state(&mut view, path, AttribKey::Hovered);
+                }
+                let mut vdom = app.style_sheet().apply(view);
+                disable_children(&mut vdom, false);
+                vdom
+            };
+            // the layout of the drawn controls, used to find the widget under the pointer
+            let mut drawn_layout = None;
+            // the colors of the theme are set before drawing,
+            // the colors of the terminal are kept when there is no theme
+            match self.app.borrow().theme() {
+                Some(theme) => crossterm::queue!(
+                    w,
+                    style::SetForegroundColor(to_term_color(theme.palette.foreground)),
+                    style::SetBackgroundColor(to_term_color(theme.palette.background)),
+                    terminal::Clear(terminal::ClearType::All)
+                )?,
+                None => crossterm::queue!(w, style::ResetColor)?,
+            }
+            if screen_reader::is_enabled() {
+                crossterm::queue!(w, terminal::Clear(terminal::ClearType::All))?;
+                titik::command::reset_top(w)?;
+                let mut lines = screen_reader::lines(&vdom);
+                lines.extend(self.live_regions.announce(&vdom));
+                for line in lines {
+                    // the terminal is in raw mode, so the lines are returned explicitly
+                    write!(w, "{}\r\n", line)?;
+                }
+            } else {
+                let mut control = Self::from_node_tree(&vdom);
+                control.set_size(Some(width as f32), Some(height as f32));
+
+                let layout_tree = titik::compute_layout(
+                    &mut control,
+                    Size {
+                        width: Number::Defined(width as f32),
+                        height: Number::Defined(height as f32),
+                    },
+                );
+                titik::command::reset_top(w)?;
+                let mut buf = Buffer::new(width as usize, height as usize);
+                control.draw(&mut buf, &layout_tree);
+                draw_static(&vdom, &layout_tree, &mut buf);
+                write!(w, "{}", buf);
+                drawn_layout = Some(layout_tree);
+            }
             w.flush()?;
 
-            if let Ok(ev) = crossterm::event::read() {
-                match ev {
-                    Event::Key(KeyEvent {
-                        code: KeyCode::Char(c),
-                        ..
-                    }) => {
-                        if c == 'q' {
-                            break;
+            // wait for an event, or for the next tick while there are timers or futures
+            let ev = loop {
+                let is_idle = self.timers.borrow().is_empty() && self.executor.borrow().is_empty();
+                if is_idle || crossterm::event::poll(TICK_RATE)? {
+                    break crossterm::event::read().ok();
+                }
+                let mut messages = self.timers.borrow_mut().fire(&self.subscriptions.borrow());
+                messages.extend(self.executor.borrow_mut().poll());
+                if !messages.is_empty() {
+                    for msg in messages {
+                        quit |= self.update_app(msg);
+                    }
+                    // draw the view updated by the timers
+                    break None;
+                }
+            };
+            match ev {
+                Some(Event::Key(TermKeyEvent {
+                    code: KeyCode::Char('c'),
+                    modifiers: KeyModifiers::CONTROL,
+                })) => break,
+                Some(Event::Key(term_key_event)) => {
+                    let key_event = to_key_event(term_key_event);
+                    let (messages, is_tab) = self.focus.handle_key(&vdom, &key_event);
+                    for msg in messages {
+                        quit |= self.update_app(msg);
+                    }
+                    // the key press is delivered to the focused widget,
+                    // or to the root widget when none has the focus
+                    let focused = self.focus.focused_element(&vdom);
+                    match target_callback(AttribKey::KeyPressEvent, &vdom, focused) {
+                        Some(cb) if !is_tab => quit |= self.update_app(cb.emit(key_event)),
+                        _ => (),
+                    }
+                }
+                Some(Event::Mouse(term_mouse_event)) => {
+                    if let Some(layout_tree) = &drawn_layout {
+                        let mut messages = vec![];
+                        if let TermMouseEvent::Down(TermMouseButton::Left, column, row, _) =
+                            term_mouse_event
+                        {
+                            messages.extend(toggle_at(&vdom, layout_tree, column, row));
+                        }
+                        let pointer = self.pointer_messages(&vdom, layout_tree, term_mouse_event);
+                        messages.extend(pointer);
+                        for msg in messages {
+                            quit |= self.update_app(msg);
                         }
                     }
-                    _ => (),
                 }
+                Some(Event::Resize(new_width, new_height)) => {
+                    width = new_width;
+                    height = new_height;
+                    let messages = self.subscriptions.borrow().resize_messages(
+                        (f64::from(width) * PIXELS_PER_COLUMN) as i32,
+                        (f64::from(height) * PIXELS_PER_ROW) as i32,
+                    );
+                    for msg in messages {
+                        quit |= self.update_app(msg);
+                    }
+                }
+                _ => (),
+            }
+            if quit {
+                break;
             }
         }
+        crossterm::execute!(w, event::DisableMouseCapture)?;
         titik::command::finalize(w);
         Ok(())
     }
 
-    fn from_node_tree(widget_node: crate::Node<MSG>) -> Control
+    /// the messages of the pointer events of the widgets under the pointer,
+    /// a click, a move or a wheel goes to the innermost widget first then to its parents.
+    /// The terminal reports the moves only while a button is held
+    fn pointer_messages(
+        &self,
+        vdom: &Node<MSG>,
+        layout_tree: &LayoutTree,
+        term_mouse_event: TermMouseEvent,
+    ) -> Vec<MSG> {
+        let (column, row) = match term_mouse_event {
+            TermMouseEvent::Down(_, column, row, _)
+            | TermMouseEvent::Up(_, column, row, _)
+            | TermMouseEvent::Drag(_, column, row, _)
+            | TermMouseEvent::ScrollDown(column, row, _)
+            | TermMouseEvent::ScrollUp(column, row, _) => (column, row),
+        };
+        let under_pointer = elements_at(vdom, layout_tree, column, row);
+        let mut messages = vec![];
+        // the widgets the pointer left, then the widgets it entered
+        let hovered: Vec<Vec<usize>> =
+            under_pointer.iter().map(|(path, ..)| path.clone()).collect();
+        let left = self.hovered.replace(hovered.clone());
+        for path in left.iter().rev().filter(|path| !hovered.contains(path)) {
+            if let Some(element) = element_at(vdom, path) {
+                let leave = pointer_event("mouseleave", Coordinate::default(), MouseButton::Left);
+                messages.extend(emit_pointer(element, AttribKey::MouseLeaveEvent, leave));
+            }
+        }
+        for (path, element, coordinate) in under_pointer.iter() {
+            if !left.contains(path) {
+                let enter = pointer_event("mouseenter", coordinate.clone(), MouseButton::Left);
+                messages.extend(emit_pointer(element, AttribKey::MouseEnterEvent, enter));
+            }
+        }
+        let mut events = vec![];
+        match term_mouse_event {
+            TermMouseEvent::Up(button, ..) => {
+                let buttons = to_mouse_button(button);
+                events.push((AttribKey::ClickEvent, "click", buttons.clone()));
+                let now = Instant::now();
+                let is_double = match self.last_click.get() {
+                    Some((last, last_column, last_row)) => {
+                        now.duration_since(last) <= DOUBLE_CLICK_TIME
+                            && (last_column, last_row) == (column, row)
+                    }
+                    None => false,
+                };
+                if is_double {
+                    events.push((AttribKey::DoubleClickEvent, "dblclick", buttons));
+                    self.last_click.set(None);
+                } else {
+                    self.last_click.set(Some((now, column, row)));
+                }
+            }
+            TermMouseEvent::Drag(button, ..) => {
+                events.push((AttribKey::MouseMoveEvent, "mousemove", to_mouse_button(button)));
+            }
+            TermMouseEvent::ScrollUp(..) => {
+                events.push((AttribKey::WheelEvent, "wheel", MouseButton::WheelUp));
+            }
+            TermMouseEvent::ScrollDown(..) => {
+                events.push((AttribKey::WheelEvent, "wheel", MouseButton::WheelDown));
+            }
+            TermMouseEvent::Down(..) => (),
+        }
+        for (key, event_type, buttons) in events {
+            for (_, element, coordinate) in under_pointer.iter().rev() {
+                let mouse_event = pointer_event(event_type, coordinate.clone(), buttons.clone());
+                messages.extend(emit_pointer(element, key.clone(), mouse_event));
+            }
+        }
+        messages
+    }
+
+    /// update the app and execute its commands,
+    /// returns whether the app requested to quit
+    fn update_app(&self, msg: MSG) -> bool {
+        let cmd = self.app.borrow_mut().update(msg);
+        self.after_update(cmd)
+    }
+
+    /// execute the command of an update or of the init,
+    /// and read the subscriptions again
+    fn after_update(&self, cmd: Cmd<MSG>) -> bool {
+        *self.subscriptions.borrow_mut() = self.app.borrow().subscriptions();
+        self.timers.borrow_mut().sync(&self.subscriptions.borrow());
+        let mut quit = false;
+        for command in cmd.into_commands() {
+            match command {
+                Command::Effect(effect) => {
+                    if let Some(msg) = effect() {
+                        quit |= self.update_app(msg);
+                    }
+                }
+                Command::Perform(future) => self.executor.borrow_mut().spawn(future),
+                Command::Quit => quit = true,
+                Command::Focus(id) => {
+                    let view = self.app.borrow().view();
+                    for msg in self.focus.focus_id(&view, &id) {
+                        quit |= self.update_app(msg);
+                    }
+                }
+                // the controls are not scrollable
+                Command::ScrollTo(_) => (),
+            }
+        }
+        quit
+    }
+
+    fn from_node_tree(widget_node: &crate::Node<MSG>) -> Control
     where
         MSG: Debug + 'static,
     {
         match widget_node {
             crate::Node::Element(element) => {
-                let mut control = Self::from_node(element.tag, &element.attrs);
-                for child in element.children {
-                    let child_widget = Self::from_node_tree(child);
-                    control.add_child(child_widget);
+                let collapsed = element.tag == Widget::Expander
+                    && !find_value(AttribKey::Expanded, &element.attrs)
+                        .map(|v| v.as_bool())
+                        .flatten()
+                        .unwrap_or(false);
+                let mut control = Self::from_node(&element.tag, &element.attrs);
+                let sizing = Sizing::from_attrs(&element.attrs);
+                apply_sizing(&mut control, &sizing);
+                if element.tag == Widget::Vbox || element.tag == Widget::Hbox {
+                    apply_alignment(&mut control, &element.attrs);
+                }
+                let visible = find_value(AttribKey::Visible, &element.attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(true);
+                if !visible {
+                    // the hidden control stays in the tree with no space in the layout
+                    control.set_size(Some(0.0), Some(0.0));
+                }
+                if !collapsed {
+                    for (index, child) in element.children.iter().enumerate() {
+                        let mut child_widget = Self::from_node_tree(child);
+                        if let (Some(spacing), true) = (sizing.spacing, index > 0) {
+                            apply_spacing(&mut child_widget, spacing, element.tag == Widget::Hbox);
+                        }
+                        control.add_child(child_widget);
+                    }
                 }
                 control
             }
@@ -100,7 +383,7 @@ where
         }
     }
 
-    fn from_node(widget: Widget, attrs: &Vec<Attribute<MSG>>) -> Control
+    fn from_node(widget: &Widget, attrs: &Vec<Attribute<MSG>>) -> Control
     where
         MSG: Debug + 'static,
     {
@@ -115,24 +398,82 @@ where
                 hbox.horizontal();
                 hbox.into()
             }
+            Widget::Frame => {
+                // titik has no titled container, the box leaves a cell on every side
+                // where `draw_static` draws the border and the title
+                let mut vbox = Box::new();
+                vbox.vertical();
+                let mut control: Control = vbox.into();
+                let mut style: Style = control.style();
+                style.border = Rect {
+                    start: Dimension::Points(1.0),
+                    end: Dimension::Points(1.0),
+                    top: Dimension::Points(1.0),
+                    bottom: Dimension::Points(1.0),
+                };
+                control.set_style(style);
+                control
+            }
+            Widget::Expander => {
+                let header = find_value(AttribKey::Label, &attrs)
+                    .map(|v| v.to_string())
+                    .unwrap_or(String::new());
+                let expanded = find_value(AttribKey::Expanded, &attrs)
+                    .map(|v| v.as_bool())
+                    .flatten()
+                    .unwrap_or(false);
+                let arrow = if expanded { "▾" } else { "▸" };
+
+                let mut vbox = Box::new();
+                vbox.vertical();
+                let mut control: Control = vbox.into();
+                control.add_child(Button::new(&format!("{} {}", arrow, header)).into());
+                control
+            }
+            Widget::Separator(orientation) => {
+                // the line is drawn by `draw_static` across the size of the box
+                let mut control: Control = Box::new().into();
+                match orientation {
+                    Orientation::Horizontal => control.set_size(None, Some(1.0)),
+                    Orientation::Vertical => control.set_size(Some(1.0), None),
+                }
+                control
+            }
             Widget::Button => {
                 let label = find_value(AttribKey::Label, &attrs)
                     .map(|v| v.to_string())
                     .unwrap_or(String::new());
 
+                let label = match find_value(AttribKey::Icon, &attrs)
+                    .map(Icon::from_value)
+                    .flatten()
+                {
+                    Some(btn_icon) => format!("{} {}", btn_icon.glyph(), label),
+                    None => label,
+                };
+
                 let btn = Button::new(&label);
                 btn.into()
             }
-            Widget::Text(txt) => {
-                let input = TextInput::new(txt);
-                input.into()
+            Widget::Text(_)
+            | Widget::Icon(..)
+            | Widget::LineChart(_)
+            | Widget::BarChart(_)
+            | Widget::Sparkline(_) => {
+                // an empty box the size of the content, which `draw_static` draws into
+                let lines = static_lines(widget, 0, 0).unwrap_or_default();
+                let width = lines.iter().map(|line| line.chars().count()).max();
+                let mut control: Control = Box::new().into();
+                control.set_size(Some(width.unwrap_or(0) as f32), Some(lines.len() as f32));
+                control
             }
             Widget::TextInput => {
                 let value = find_value(AttribKey::Value, &attrs)
                     .map(|v| v.to_string())
                     .unwrap_or(String::new());
-                let input = TextInput::new(value);
-                input.into()
+                // the placeholder is drawn over the empty input by `draw_static`,
+                // so it is not part of the content which is edited
+                TextInput::new(value).into()
             }
             Widget::Checkbox => {
                 let label = find_value(AttribKey::Label, &attrs)
@@ -163,7 +504,7 @@ where
                 rb.into()
             }
             Widget::Image(bytes) => {
-                let mut img = Image::new(bytes);
+                let mut img = Image::new(bytes.clone());
                 img.set_size(Some(100.0), Some(50.0));
                 img.into()
             }
@@ -171,14 +512,458 @@ where
     }
 }
 
+/// the content of the widgets which only show text or lines,
+/// titik has no control for them so they are drawn into the buffer
+/// over an empty box which takes their place in the layout
+fn static_lines(widget: &Widget, width: usize, height: usize) -> Option<Vec<String>> {
+    match widget {
+        Widget::Text(txt) => Some(txt.lines().map(ToString::to_string).collect()),
+        Widget::Separator(Orientation::Horizontal) => Some(vec!["─".repeat(width)]),
+        Widget::Separator(Orientation::Vertical) => Some(vec!["│".to_string(); height]),
+        Widget::Icon(icon, _size) => Some(vec![icon.glyph().to_string()]),
+        // each of the series is drawn with block characters
+        Widget::LineChart(chart) | Widget::BarChart(chart) => Some(
+            chart
+                .series
+                .iter()
+                .map(|series| format!("{} {}", series.to_block_chars(), series.name))
+                .collect(),
+        ),
+        Widget::Sparkline(series) => Some(vec![series.to_block_chars()]),
+        _ => None,
+    }
+}
+
+/// the cells covered by a control, in the coordinates of the terminal
+#[derive(Clone, Copy, Debug)]
+struct Area {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl Area {
+    fn contains(&self, column: u16, row: u16) -> bool {
+        let (column, row) = (f32::from(column), f32::from(row));
+        column >= self.x
+            && column < self.x + self.width
+            && row >= self.y
+            && row < self.y + self.height
+    }
+}
+
+/// visit the elements of the view in order with their path of child indices
+/// and the layout of their control, the controls are built by `from_node_tree`
+/// so their tree follows the view, except for the header which is the first control
+/// of an expander
+fn walk_layout<'a, MSG, F>(
+    node: &'a Node<MSG>,
+    layout_tree: &LayoutTree,
+    origin: (f32, f32),
+    path: &mut Vec<usize>,
+    f: &mut F,
+) where
+    MSG: 'static,
+    F: FnMut(&[usize], &'a Element<MSG>, &LayoutTree, Area),
+{
+    if let Node::Element(element) = node {
+        let layout = &layout_tree.layout;
+        let area = Area {
+            x: origin.0 + layout.location.x,
+            y: origin.1 + layout.location.y,
+            width: layout.size.width,
+            height: layout.size.height,
+        };
+        f(path, element, layout_tree, area);
+        let skip = if element.tag == Widget::Expander { 1 } else { 0 };
+        for (index, (child, child_layout)) in element
+            .children
+            .iter()
+            .zip(layout_tree.children_layout.iter().skip(skip))
+            .enumerate()
+        {
+            path.push(index);
+            walk_layout(child, child_layout, (area.x, area.y), path, f);
+            path.pop();
+        }
+    }
+}
+
+/// the children of a disabled widget are disabled along with it,
+/// so none of them is clicked or toggled
+fn disable_children<MSG>(node: &mut Node<MSG>, parent_disabled: bool)
+where
+    MSG: 'static,
+{
+    if let Node::Element(element) = node {
+        let disabled = is_disabled(element);
+        if parent_disabled && !disabled {
+            element.attrs.push(attr(AttribKey::Disabled, true));
+        }
+        for child in element.children.iter_mut() {
+            disable_children(child, parent_disabled || disabled);
+        }
+    }
+}
+
+fn is_disabled<MSG>(element: &Element<MSG>) -> bool
+where
+    MSG: 'static,
+{
+    find_value(AttribKey::Disabled, &element.attrs)
+        .map(|v| v.as_bool())
+        .flatten()
+        .unwrap_or(false)
+}
+
+/// draw the content of the static widgets into the boxes laid out for them,
+/// and dim the disabled widgets
+fn draw_static<MSG>(node: &Node<MSG>, layout_tree: &LayoutTree, buf: &mut Buffer)
+where
+    MSG: 'static,
+{
+    walk_layout(node, layout_tree, (0.0, 0.0), &mut vec![], &mut |_, element, _, area| {
+        let (width, height) = (area.width as usize, area.height as usize);
+        match element.tag {
+            Widget::TextInput => draw_placeholder(element, area, buf),
+            Widget::Frame => draw_frame(element, area, buf),
+            _ => (),
+        }
+        if let Some(lines) = static_lines(&element.tag, width, height) {
+            for (row, line) in lines.iter().take(height).enumerate() {
+                for (column, ch) in line.chars().take(width).enumerate() {
+                    buf.set_symbol(area.x as usize + column, area.y as usize + row, ch);
+                }
+            }
+        }
+        if is_disabled(element) {
+            for row in 0..height {
+                for column in 0..width {
+                    let (x, y) = (area.x as usize + column, area.y as usize + row);
+                    if let Some(cell) = buf.get_cell_mut(x, y) {
+                        cell.attribute(style::Attribute::Dim);
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// the border of a frame, with its label as the title on the top border
+fn draw_frame<MSG>(element: &Element<MSG>, area: Area, buf: &mut Buffer)
+where
+    MSG: 'static,
+{
+    let (x, y) = (area.x as usize, area.y as usize);
+    let (width, height) = (area.width as usize, area.height as usize);
+    if width < 2 || height < 2 {
+        return;
+    }
+    let (right, bottom) = (x + width - 1, y + height - 1);
+    for column in x + 1..right {
+        buf.set_symbol(column, y, '─');
+        buf.set_symbol(column, bottom, '─');
+    }
+    for row in y + 1..bottom {
+        buf.set_symbol(x, row, '│');
+        buf.set_symbol(right, row, '│');
+    }
+    buf.set_symbol(x, y, '┌');
+    buf.set_symbol(right, y, '┐');
+    buf.set_symbol(x, bottom, '└');
+    buf.set_symbol(right, bottom, '┘');
+    if let Some(title) = find_value(AttribKey::Label, &element.attrs).map(|v| v.to_string()) {
+        for (column, ch) in title.chars().take(width - 2).enumerate() {
+            buf.set_symbol(x + 1 + column, y, ch);
+        }
+    }
+}
+
+/// the placeholder of an empty input, inside the border of the input
+fn draw_placeholder<MSG>(element: &Element<MSG>, area: Area, buf: &mut Buffer)
+where
+    MSG: 'static,
+{
+    let value = find_value(AttribKey::Value, &element.attrs).map(|v| v.to_string());
+    let placeholder = find_value(AttribKey::Placeholder, &element.attrs).map(|v| v.to_string());
+    if let (None, Some(placeholder)) = (value.filter(|value| !value.is_empty()), placeholder) {
+        let (x, y) = (area.x as usize + 1, area.y as usize + 1);
+        let width = (area.width as usize).saturating_sub(2);
+        for (column, ch) in placeholder.chars().take(width).enumerate() {
+            buf.set_symbol(x + column, y, ch);
+        }
+    }
+}
+
+/// clicking on a checkbox, a radio or the header of an expander emits its toggle event
+/// with the new state, the header is the first control of the expander
+fn toggle_at<MSG>(node: &Node<MSG>, layout_tree: &LayoutTree, column: u16, row: u16) -> Option<MSG>
+where
+    MSG: 'static,
+{
+    let mut msg = None;
+    let mut visit = |_: &[usize], element: &Element<MSG>, layout_tree: &LayoutTree, area: Area| {
+        let flag = |key| {
+            find_value(key, &element.attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+        };
+        // the children of a disabled widget are also marked disabled
+        if is_disabled(element) {
+            return;
+        }
+        let (toggle_area, state_key) = match element.tag {
+            Widget::Checkbox | Widget::Radio => (area, AttribKey::Value),
+            Widget::Expander => match layout_tree.children_layout.first() {
+                Some(header) => {
+                    let header_area = Area {
+                        x: area.x + header.layout.location.x,
+                        y: area.y + header.layout.location.y,
+                        width: header.layout.size.width,
+                        height: header.layout.size.height,
+                    };
+                    (header_area, AttribKey::Expanded)
+                }
+                None => return,
+            },
+            _ => return,
+        };
+        if toggle_area.contains(column, row) {
+            // a radio stays checked when it is clicked again
+            let new_state = element.tag == Widget::Radio || !flag(state_key).unwrap_or(false);
+            msg = find_callback(AttribKey::ToggleEvent, &element.attrs)
+                .map(|cb| cb.emit(InputEvent::new(new_state.to_string())));
+        }
+    };
+    walk_layout(node, layout_tree, (0.0, 0.0), &mut vec![], &mut visit);
+    msg
+}
+
+/// the elements under the pointer from the root widget to the innermost widget,
+/// with their path of child indices and the position of the pointer in their area
+fn elements_at<'a, MSG>(
+    node: &'a Node<MSG>,
+    layout_tree: &LayoutTree,
+    column: u16,
+    row: u16,
+) -> Vec<(Vec<usize>, &'a Element<MSG>, Coordinate)>
+where
+    MSG: 'static,
+{
+    let mut elements = vec![];
+    walk_layout(node, layout_tree, (0.0, 0.0), &mut vec![], &mut |path, element, _, area| {
+        if area.contains(column, row) {
+            let coordinate =
+                Coordinate::new(i32::from(column) - area.x as i32, i32::from(row) - area.y as i32);
+            elements.push((path.to_vec(), element, coordinate));
+        }
+    });
+    elements
+}
+
+/// the element at this path of child indices in the view
+fn element_at<'a, MSG>(node: &'a Node<MSG>, path: &[usize]) -> Option<&'a Element<MSG>> {
+    match (node, path.split_first()) {
+        (Node::Element(element), None) => Some(element),
+        (Node::Element(element), Some((index, rest))) => {
+            element.children.get(*index).and_then(|child| element_at(child, rest))
+        }
+        (Node::Text(_), _) => None,
+    }
+}
+
+/// the message of the pointer event of an enabled widget,
+/// the children of a disabled widget are also marked disabled
+fn emit_pointer<MSG>(element: &Element<MSG>, key: AttribKey, mouse_event: MouseEvent) -> Option<MSG>
+where
+    MSG: 'static,
+{
+    if is_disabled(element) {
+        return None;
+    }
+    find_callback(key, &element.attrs).map(|cb| cb.emit(mouse_event))
+}
+
+fn pointer_event(
+    event_type: &'static str,
+    coordinate: Coordinate,
+    buttons: MouseButton,
+) -> MouseEvent {
+    MouseEvent {
+        r#type: event_type,
+        coordinate,
+        buttons,
+        ..Default::default()
+    }
+}
+
+fn to_mouse_button(button: TermMouseButton) -> MouseButton {
+    match button {
+        TermMouseButton::Left => MouseButton::Left,
+        TermMouseButton::Right => MouseButton::Right,
+        TermMouseButton::Middle => MouseButton::Middle,
+    }
+}
+
+fn to_term_color(color: property::Color) -> style::Color {
+    style::Color::Rgb {
+        r: color.red,
+        g: color.green,
+        b: color.blue,
+    }
+}
+
+/// the keys are named the same as the key of the html keyboard event,
+/// the terminal can not tell whether a key is repeating
+fn to_key_event(term_key_event: TermKeyEvent) -> KeyEvent {
+    let key = match term_key_event.code {
+        KeyCode::Char(ch) => ch.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Left => "ArrowLeft".to_string(),
+        KeyCode::Right => "ArrowRight".to_string(),
+        KeyCode::Up => "ArrowUp".to_string(),
+        KeyCode::Down => "ArrowDown".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Tab | KeyCode::BackTab => "Tab".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Insert => "Insert".to_string(),
+        KeyCode::Esc => "Escape".to_string(),
+        KeyCode::Null => "Unidentified".to_string(),
+    };
+    let modifiers = term_key_event.modifiers;
+    KeyEvent {
+        key,
+        modifier: Modifier {
+            alt_key: modifiers.contains(KeyModifiers::ALT),
+            ctrl_key: modifiers.contains(KeyModifiers::CONTROL),
+            shift_key: modifiers.contains(KeyModifiers::SHIFT)
+                || term_key_event.code == KeyCode::BackTab,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// the pixel lengths are converted into cells,
+/// and the percentages into the fraction of the parent used by stretch
+fn dimension(length: Length, cell_size: f64) -> Dimension {
+    match length {
+        Length::Px(px) => Dimension::Points((px / cell_size).round() as f32),
+        Length::Percent(percent) => Dimension::Percent((percent / 100.0) as f32),
+    }
+}
+
+/// the same space in pixels on every side, in columns at the start and the end
+/// and in rows at the top and the bottom
+fn cells_rect(px: f64) -> Rect<Dimension> {
+    Rect {
+        start: dimension(Length::Px(px), PIXELS_PER_COLUMN),
+        end: dimension(Length::Px(px), PIXELS_PER_COLUMN),
+        top: dimension(Length::Px(px), PIXELS_PER_ROW),
+        bottom: dimension(Length::Px(px), PIXELS_PER_ROW),
+    }
+}
+
+/// the sizing is set on the stretch style of the control,
+/// which titik uses in its layout
+fn apply_sizing(control: &mut Control, sizing: &Sizing) {
+    let mut style: Style = control.style();
+    let columns = |length: Length| dimension(length, PIXELS_PER_COLUMN);
+    let rows = |length: Length| dimension(length, PIXELS_PER_ROW);
+    if let Some(width) = sizing.width {
+        style.size.width = columns(width);
+    }
+    if let Some(height) = sizing.height {
+        style.size.height = rows(height);
+    }
+    if let Some(min_width) = sizing.min_width {
+        style.min_size.width = columns(min_width);
+    }
+    if let Some(min_height) = sizing.min_height {
+        style.min_size.height = rows(min_height);
+    }
+    if let Some(max_width) = sizing.max_width {
+        style.max_size.width = columns(max_width);
+    }
+    if let Some(max_height) = sizing.max_height {
+        style.max_size.height = rows(max_height);
+    }
+    if let Some(padding) = sizing.padding {
+        style.padding = cells_rect(padding);
+    }
+    if let Some(margin) = sizing.margin {
+        style.margin = cells_rect(margin);
+    }
+    if let Some(flex_grow) = sizing.flex_grow {
+        style.flex_grow = flex_grow as f32;
+    }
+    if let Some(flex_shrink) = sizing.flex_shrink {
+        style.flex_shrink = flex_shrink as f32;
+    }
+    control.set_style(style);
+}
+
+/// the children of a box are aligned with the flex properties of its style
+fn apply_alignment<MSG>(control: &mut Control, attrs: &Vec<Attribute<MSG>>)
+where
+    MSG: 'static,
+{
+    let alignment = |key| find_value(key, attrs).map(Alignment::from_value).flatten();
+    let mut style: Style = control.style();
+    if let Some(alignment) = alignment(AttribKey::Alignment) {
+        style.justify_content = match alignment {
+            Alignment::Start | Alignment::Stretch => JustifyContent::FlexStart,
+            Alignment::Center => JustifyContent::Center,
+            Alignment::End => JustifyContent::FlexEnd,
+            Alignment::SpaceBetween => JustifyContent::SpaceBetween,
+        };
+    }
+    if let Some(cross_alignment) = alignment(AttribKey::CrossAlignment) {
+        style.align_items = match cross_alignment {
+            Alignment::Start => AlignItems::FlexStart,
+            Alignment::Center => AlignItems::Center,
+            Alignment::End => AlignItems::FlexEnd,
+            Alignment::Stretch | Alignment::SpaceBetween => AlignItems::Stretch,
+        };
+    }
+    control.set_style(style);
+}
+
+/// stretch has no gap between the children of a box,
+/// the spacing is the margin before each child after the first
+fn apply_spacing(control: &mut Control, spacing: f64, horizontal: bool) {
+    let mut style: Style = control.style();
+    if horizontal {
+        style.margin.start = dimension(Length::Px(spacing), PIXELS_PER_COLUMN);
+    } else {
+        style.margin.top = dimension(Length::Px(spacing), PIXELS_PER_ROW);
+    }
+    control.set_style(style);
+}
+
 impl<APP, MSG> Backend<APP, MSG> for TitikBackend<APP, MSG>
 where
     APP: Component<MSG> + 'static,
     MSG: Debug + 'static,
 {
     fn init(app: APP) -> Rc<Self> {
+        let subscriptions = app.subscriptions();
         let backend = TitikBackend {
             app: Rc::new(RefCell::new(app)),
+            subscriptions: RefCell::new(subscriptions),
+            timers: RefCell::new(Timers::new()),
+            // the futures are polled on the ticks, which do not need to be woken up
+            executor: RefCell::new(Executor::new(|| ())),
+            focus: Focus::new(),
+            live_regions: LiveRegions::default(),
+            hovered: RefCell::new(vec![]),
+            last_click: Cell::new(None),
             _phantom_msg: PhantomData,
         };
         let rc_backend = Rc::new(backend);
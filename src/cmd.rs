@@ -0,0 +1,151 @@
+//! Commands returned from the update of a component, which the backend
+//! executes once the view is updated, such as dispatching follow-up messages,
+//! running side effects and futures, and requesting to quit, focus or scroll.
+use std::{future::Future, pin::Pin, rc::Rc};
+
+/// What a command asks of the backend
+pub(crate) enum Command<MSG> {
+    /// run by the backend, the message returned is dispatched
+    Effect(Box<dyn FnOnce() -> Option<MSG>>),
+    /// polled on the event loop of the backend, the message it completes with is dispatched
+    Perform(Pin<Box<dyn Future<Output = MSG>>>),
+    /// close the app, the html backend can not close the browser tab
+    Quit,
+    /// move the focus to the widget with this id
+    Focus(String),
+    /// scroll the widget with this id into view
+    ScrollTo(String),
+}
+
+/// The commands from an update, executed in order
+pub struct Cmd<MSG> {
+    commands: Vec<Command<MSG>>,
+}
+
+impl<MSG> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    pub fn none() -> Self {
+        Cmd { commands: vec![] }
+    }
+
+    /// dispatch this message after the update
+    pub fn msg(msg: MSG) -> Self {
+        Self::effect(move || Some(msg))
+    }
+
+    /// run the effect after the update, such as saving the state,
+    /// the message it returns is dispatched
+    pub fn effect<F>(effect: F) -> Self
+    where
+        F: FnOnce() -> Option<MSG> + 'static,
+    {
+        Cmd {
+            commands: vec![Command::Effect(Box::new(effect))],
+        }
+    }
+
+    /// run the future on the event loop of the backend, the ui keeps running while it is
+    /// pending, its output is made into the message dispatched once it completes
+    pub fn perform<F, C>(future: F, msg: C) -> Self
+    where
+        F: Future + 'static,
+        C: FnOnce(F::Output) -> MSG + 'static,
+    {
+        Cmd {
+            commands: vec![Command::Perform(Box::pin(async move { msg(future.await) }))],
+        }
+    }
+
+    pub fn batch(cmds: Vec<Cmd<MSG>>) -> Self {
+        Cmd {
+            commands: cmds.into_iter().flat_map(|cmd| cmd.commands).collect(),
+        }
+    }
+
+    pub fn quit() -> Self {
+        Cmd {
+            commands: vec![Command::Quit],
+        }
+    }
+
+    /// unlike `Component::focus`, the focus is moved even if the id is the same as before
+    pub fn focus(id: &str) -> Self {
+        Cmd {
+            commands: vec![Command::Focus(id.to_string())],
+        }
+    }
+
+    /// only the html backend has scrollable content
+    pub fn scroll_to(id: &str) -> Self {
+        Cmd {
+            commands: vec![Command::ScrollTo(id.to_string())],
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// wrap the messages of the command of a sub component into the messages of its parent
+    pub fn map_msg<F, PMSG>(self, f: F) -> Cmd<PMSG>
+    where
+        F: Fn(MSG) -> PMSG + 'static,
+        PMSG: 'static,
+    {
+        let f = Rc::new(f);
+        let commands = self
+            .commands
+            .into_iter()
+            .map(|command| match command {
+                Command::Effect(effect) => {
+                    let f = Rc::clone(&f);
+                    Command::Effect(Box::new(move || effect().map(&*f)))
+                }
+                Command::Perform(future) => {
+                    let f = Rc::clone(&f);
+                    Command::Perform(Box::pin(async move { f(future.await) }))
+                }
+                Command::Quit => Command::Quit,
+                Command::Focus(id) => Command::Focus(id),
+                Command::ScrollTo(id) => Command::ScrollTo(id),
+            })
+            .collect();
+        Cmd { commands }
+    }
+
+    pub(crate) fn into_commands(self) -> Vec<Command<MSG>> {
+        self.commands
+    }
+}
+
+impl<MSG> Default for Cmd<MSG>
+where
+    MSG: 'static,
+{
+    fn default() -> Self {
+        Cmd::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_msg_of_effects() {
+        let cmd = Cmd::batch(vec![Cmd::msg(1), Cmd::focus("name"), Cmd::msg(2)]);
+        let commands = cmd.map_msg(|n: i32| n * 10).into_commands();
+        assert_eq!(commands.len(), 3);
+        let mut messages = vec![];
+        for command in commands {
+            match command {
+                Command::Effect(effect) => messages.extend(effect()),
+                Command::Focus(id) => assert_eq!(id, "name"),
+                _ => panic!("unexpected command"),
+            }
+        }
+        assert_eq!(messages, vec![10, 20]);
+    }
+}
@@ -1,7 +1,14 @@
-use crate::{Event, Node};
+use crate::{Cmd, Event, Node, StyleSheet, Sub, Theme};
 
 pub trait Component<MSG> {
-    fn update(&mut self, msg: MSG);
+    /// called once by the backend before the first event, such as to start loading data,
+    /// the command returned is executed once the view is mounted
+    fn init(&mut self) -> Cmd<MSG> {
+        Cmd::none()
+    }
+
+    /// the command returned is executed by the backend once the view is updated
+    fn update(&mut self, msg: MSG) -> Cmd<MSG>;
 
     fn on_event(&mut self, event: Event) {
         // only tui backend use this
@@ -12,4 +19,29 @@ pub trait Component<MSG> {
     }
 
     fn view(&self) -> Node<MSG>;
+
+    /// the theme is read again after every update,
+    /// so the app can switch it at runtime.
+    /// Without a theme the widgets keep the native look of the backend
+    fn theme(&self) -> Option<Theme> {
+        None
+    }
+
+    /// the id of the widget which should have the focus, read again after
+    /// every update, the focus is moved to the widget when the id changes
+    fn focus(&self) -> Option<String> {
+        None
+    }
+
+    /// the style sheet is resolved into the view before
+    /// it is rendered, so it applies the same in every backend
+    fn style_sheet(&self) -> StyleSheet {
+        StyleSheet::default()
+    }
+
+    /// the subscriptions are read again after every update,
+    /// the backend stops listening to those which are no longer returned
+    fn subscriptions(&self) -> Sub<MSG> {
+        Sub::none()
+    }
 }
@@ -4,19 +4,32 @@
 #![feature(arbitrary_self_types)]
 
 pub mod event {
-    pub use sauron_vdom::{builder::on, event::InputEvent, Event};
+    pub use sauron_vdom::{
+        builder::on,
+        event::{Coordinate, InputEvent, KeyEvent, Modifier, MouseButton, MouseEvent},
+        Event,
+    };
 }
 use std::fmt;
 
 pub mod backend;
+mod cmd;
 mod component;
 mod program;
+pub mod style;
+mod sub;
+pub mod theme;
 pub mod widget;
 
 pub use backend::Backend;
+pub use cmd::Cmd;
 pub use component::Component;
 pub use program::Program;
+pub use sauron_native_macro::view;
 pub use sauron_vdom::{builder, Callback, Event, Value};
+pub use style::StyleSheet;
+pub use sub::Sub;
+pub use theme::Theme;
 pub use widget::Widget;
 
 /// TODO: replace the &'static str attribute key as an enum
@@ -29,11 +42,102 @@ pub enum AttribKey {
     Label,
     /// bool, used in checkbox, radio
     Checked,
-    /// Alignment Enum, used in hbox and vbox
+    /// Alignment Enum, used in hbox and vbox along their direction,
+    /// and in text for the alignment of the text
     Alignment,
+    /// Alignment Enum, used in hbox and vbox across their direction
+    CrossAlignment,
+    /// BorderStyle Enum, used in frame
+    BorderStyle,
+    /// bool, used in expander
+    Expanded,
+    /// String, the hint shown in an empty text input
+    Placeholder,
+    /// Icon Enum, used in button
+    Icon,
+    /// Length, the preferred size of any widget
+    Width,
+    Height,
+    MinWidth,
+    MinHeight,
+    MaxWidth,
+    MaxHeight,
+    /// f64 in pixels, used in any widget
+    Padding,
+    Margin,
+    /// f64 in pixels, space between the children of hbox and vbox
+    Spacing,
+    /// f64, used in the children of hbox and vbox
+    FlexGrow,
+    FlexShrink,
+    /// Color, the color of the text in any widget
+    Color,
+    /// Color, the color behind the content of any widget
+    BackgroundColor,
+    /// String, space separated class names selected by style sheet rules
+    Class,
+    /// String, a stable identifier of the widget, used to request the focus
+    /// and to find the widget with a `Selector`
+    Id,
+    /// bool, the widget takes the focus when it is shown
+    Autofocus,
+    /// String, the name of the widget read by assistive technologies,
+    /// it is the alternative text of images and of buttons showing only an icon
+    AccessibleLabel,
+    /// String, a longer description read after the name of the widget
+    AccessibleDescription,
+    /// String, overrides the role of the widget, named as the aria roles
+    /// such as "heading", "alert", "status"
+    Role,
+    /// Politeness Enum, how the changes in the content of the widget are announced,
+    /// only the html backend can mark a live region
+    Live,
+    /// bool, a hidden widget stays in the tree so it keeps its state,
+    /// any widget is visible unless this is set to false
+    Visible,
+    /// bool, the states of any widget selected by style sheet rules
+    Hovered,
+    Focused,
+    Disabled,
     ClickEvent,
+    /// emitted with a `MouseEvent` with the pointer coordinate relative to the widget
+    DoubleClickEvent,
+    MouseEnterEvent,
+    MouseLeaveEvent,
+    MouseMoveEvent,
+    /// the button of the mouse event is either `WheelUp` or `WheelDown`
+    WheelEvent,
     InputEvent,
+    /// emitted with the new state when an expander is expanded or collapsed,
+    /// or when a checkbox or radio is checked or unchecked
+    ToggleEvent,
+    /// emitted with an `InputEvent` when the widget takes or loses the focus
+    FocusEvent,
+    BlurEvent,
+    /// emitted with a `KeyEvent` when a key is pressed or released
+    KeyPressEvent,
+    KeyReleaseEvent,
     Key,
+    /// an attribute or event addressed to a single backend, named
+    /// `backend:name` such as `html:data-testid` or `gtk:tooltip-text`,
+    /// the other backends ignore it
+    Custom(String),
+}
+
+impl AttribKey {
+    /// the name of the custom attribute if it is addressed to this backend
+    pub fn custom_name(&self, backend: &str) -> Option<&str> {
+        match self {
+            AttribKey::Custom(name) => {
+                let mut parts = name.splitn(2, ':');
+                match (parts.next(), parts.next()) {
+                    (Some(prefix), Some(name)) if prefix == backend => Some(name),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for AttribKey {
@@ -46,3 +150,18 @@ pub type Node<MSG> = sauron_vdom::Node<Widget, AttribKey, Event, MSG>;
 pub type Element<MSG> = sauron_vdom::Element<Widget, AttribKey, Event, MSG>;
 pub type Patch<'a, MSG> = sauron_vdom::Patch<'a, Widget, AttribKey, Event, MSG>;
 pub type Attribute<MSG> = sauron_vdom::Attribute<AttribKey, Event, MSG>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_name_of_backend() {
+        let key = AttribKey::Custom("html:data-testid".to_string());
+        assert_eq!(key.custom_name("html"), Some("data-testid"));
+        assert_eq!(key.custom_name("gtk"), None);
+        let unprefixed = AttribKey::Custom("tooltip".to_string());
+        assert_eq!(unprefixed.custom_name("gtk"), None);
+        assert_eq!(AttribKey::Label.custom_name("html"), None);
+    }
+}
@@ -0,0 +1,328 @@
+//! Style sheets of rules which set attributes on the widgets matching their selectors.
+//! The rules are resolved into the attributes of each node before the view
+//! is handed to the backend, so one style sheet governs every backend.
+//! The selectors also find the widgets in a view, such as in the tests of an app.
+use crate::{
+    widget::attribute::{attr, find_value},
+    AttribKey, Attribute, Element, Node, Widget,
+};
+
+/// The kind of a widget, without the data it carries
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidgetKind {
+    Column,
+    Row,
+    Button,
+    Text,
+    TextInput,
+    Checkbox,
+    Radio,
+    Image,
+    Frame,
+    Separator,
+    Expander,
+    Icon,
+    /// line charts, bar charts and sparklines
+    Chart,
+}
+
+impl WidgetKind {
+    pub fn of(widget: &Widget) -> Self {
+        match widget {
+            Widget::Vbox => WidgetKind::Column,
+            Widget::Hbox => WidgetKind::Row,
+            Widget::Button => WidgetKind::Button,
+            Widget::Text(_) => WidgetKind::Text,
+            Widget::TextInput => WidgetKind::TextInput,
+            Widget::Checkbox => WidgetKind::Checkbox,
+            Widget::Radio => WidgetKind::Radio,
+            Widget::Image(_) => WidgetKind::Image,
+            Widget::Frame => WidgetKind::Frame,
+            Widget::Separator(_) => WidgetKind::Separator,
+            Widget::Expander => WidgetKind::Expander,
+            Widget::Icon(_, _) => WidgetKind::Icon,
+            Widget::LineChart(_) | Widget::BarChart(_) | Widget::Sparkline(_) => {
+                WidgetKind::Chart
+            }
+        }
+    }
+}
+
+/// The state of a widget, which the app sets with the
+/// `hovered`, `focused` and `disabled` attributes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Hover,
+    Focused,
+    Disabled,
+}
+
+impl State {
+    fn key(&self) -> AttribKey {
+        match self {
+            State::Hover => AttribKey::Hovered,
+            State::Focused => AttribKey::Focused,
+            State::Disabled => AttribKey::Disabled,
+        }
+    }
+}
+
+/// Selects the widgets a rule applies to,
+/// a widget has to match every part which is set
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Selector {
+    kind: Option<WidgetKind>,
+    id: Option<String>,
+    class: Option<String>,
+    state: Option<State>,
+}
+
+impl Selector {
+    pub fn kind(kind: WidgetKind) -> Self {
+        Selector {
+            kind: Some(kind),
+            ..Default::default()
+        }
+    }
+
+    pub fn id(id: &str) -> Self {
+        Selector {
+            id: Some(id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn class(class: &str) -> Self {
+        Selector {
+            class: Some(class.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn state(state: State) -> Self {
+        Selector {
+            state: Some(state),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_kind(mut self, kind: WidgetKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.class = Some(class.to_string());
+        self
+    }
+
+    pub fn with_state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// the number of parts set in this selector,
+    /// the rules with more specific selectors take precedence
+    fn specificity(&self) -> usize {
+        [
+            self.kind.is_some(),
+            self.id.is_some(),
+            self.class.is_some(),
+            self.state.is_some(),
+        ]
+        .iter()
+        .filter(|part| **part)
+        .count()
+    }
+
+    fn matches<MSG>(&self, widget: &Widget, attrs: &Vec<Attribute<MSG>>) -> bool
+    where
+        MSG: 'static,
+    {
+        let kind_matches = self.kind.map_or(true, |kind| kind == WidgetKind::of(widget));
+        let id_matches = self.id.as_ref().map_or(true, |id| {
+            find_value(AttribKey::Id, attrs).map(|v| v.to_string()) == Some(id.to_string())
+        });
+        let class_matches = self.class.as_ref().map_or(true, |class| {
+            find_value(AttribKey::Class, attrs)
+                .map(|classes| classes.to_string().split_whitespace().any(|c| c == class))
+                .unwrap_or(false)
+        });
+        let state_matches = self.state.map_or(true, |state| {
+            find_value(state.key(), attrs)
+                .map(|v| v.as_bool())
+                .flatten()
+                .unwrap_or(false)
+        });
+        kind_matches && id_matches && class_matches && state_matches
+    }
+
+    /// the widgets in the tree matching this selector, in the order they appear
+    pub fn find_all<'a, MSG>(&self, node: &'a Node<MSG>) -> Vec<&'a Element<MSG>>
+    where
+        MSG: 'static,
+    {
+        let mut found = vec![];
+        if let Node::Element(element) = node {
+            if self.matches(&element.tag, &element.attrs) {
+                found.push(element);
+            }
+            for child in element.children.iter() {
+                found.extend(self.find_all(child));
+            }
+        }
+        found
+    }
+
+    pub fn find<'a, MSG>(&self, node: &'a Node<MSG>) -> Option<&'a Element<MSG>>
+    where
+        MSG: 'static,
+    {
+        self.find_all(node).into_iter().next()
+    }
+}
+
+/// The attributes set on the widgets matching the selector,
+/// the attributes are made with the usual attribute functions
+pub struct Rule {
+    selector: Selector,
+    declarations: Vec<Attribute<()>>,
+}
+
+pub fn rule(selector: Selector, declarations: Vec<Attribute<()>>) -> Rule {
+    Rule {
+        selector,
+        declarations,
+    }
+}
+
+/// A list of rules, when several rules set the same attribute
+/// the more specific rule wins, then the one that comes later.
+/// The attributes set directly on a node always win over the style sheet.
+#[derive(Default)]
+pub struct StyleSheet {
+    rules: Vec<Rule>,
+}
+
+impl StyleSheet {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        StyleSheet { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// resolve the rules into the attributes of every node in the tree
+    pub fn apply<MSG>(&self, node: Node<MSG>) -> Node<MSG>
+    where
+        MSG: 'static,
+    {
+        if self.is_empty() {
+            return node;
+        }
+        let mut rules: Vec<&Rule> = self.rules.iter().collect();
+        // the rules which take precedence come first
+        rules.reverse();
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.selector.specificity()));
+        resolve(&rules, node)
+    }
+}
+
+fn resolve<MSG>(rules: &[&Rule], node: Node<MSG>) -> Node<MSG>
+where
+    MSG: 'static,
+{
+    match node {
+        Node::Element(mut element) => {
+            let mut resolved = vec![];
+            for rule in rules {
+                if !rule.selector.matches(&element.tag, &element.attrs) {
+                    continue;
+                }
+                for declaration in rule.declarations.iter() {
+                    let is_set = element.attrs.iter().any(|a| a.name == declaration.name)
+                        || resolved
+                            .iter()
+                            .any(|a: &Attribute<MSG>| a.name == declaration.name);
+                    if let (false, Some(value)) = (is_set, declaration.get_value()) {
+                        resolved.push(attr(declaration.name.clone(), value.clone()));
+                    }
+                }
+            }
+            element.attrs.extend(resolved);
+            element.children = element
+                .children
+                .into_iter()
+                .map(|child| resolve(rules, child))
+                .collect();
+            Node::Element(element)
+        }
+        Node::Text(txt) => Node::Text(txt),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{
+        attribute::{class, disabled, id, label},
+        button, column, text_input,
+    };
+
+    fn view() -> Node<()> {
+        column(
+            vec![],
+            vec![
+                button(vec![id("save"), class("primary wide"), label("Save")]),
+                text_input(vec![class("wide")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn find_by_id() {
+        let view = view();
+        let save = Selector::id("save").find(&view);
+        assert_eq!(save.map(|element| &element.tag), Some(&Widget::Button));
+        assert!(Selector::id("cancel").find(&view).is_none());
+    }
+
+    #[test]
+    fn find_all_by_class() {
+        let view = view();
+        let tags: Vec<&Widget> = Selector::class("wide")
+            .find_all(&view)
+            .into_iter()
+            .map(|element| &element.tag)
+            .collect();
+        assert_eq!(tags, vec![&Widget::Button, &Widget::TextInput]);
+        let primary_inputs = Selector::class("primary").with_kind(WidgetKind::TextInput);
+        assert!(primary_inputs.find(&view).is_none());
+    }
+
+    #[test]
+    fn matches_every_part() {
+        let attrs: Vec<Attribute<()>> = vec![
+            id("save"),
+            class("primary wide"),
+            disabled(true),
+            attr(AttribKey::Hovered, false),
+        ];
+        let selector = Selector::kind(WidgetKind::Button)
+            .with_id("save")
+            .with_class("wide")
+            .with_state(State::Disabled);
+        assert!(selector.matches(&Widget::Button, &attrs));
+        assert!(!selector.matches(&Widget::TextInput, &attrs));
+        assert!(!Selector::class("prim").matches(&Widget::Button, &attrs));
+        assert!(!Selector::state(State::Hover).matches(&Widget::Button, &attrs));
+        assert!(!Selector::state(State::Focused).matches(&Widget::Button, &attrs));
+        assert!(Selector::default().matches(&Widget::Button, &attrs));
+    }
+}
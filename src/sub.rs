@@ -0,0 +1,190 @@
+//! Subscriptions of a component to the events which are not from a widget,
+//! such as timers, animation frames and the resizing of the window.
+//! They are read again after every update, the backend starts the sources
+//! which are new and stops those which are no longer subscribed to.
+use sauron_vdom::Callback;
+use std::{rc::Rc, time::Duration};
+
+/// What a subscription listens to, the backend keeps one running source for each
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Source {
+    Every(Duration),
+    AnimationFrame,
+    Resize,
+}
+
+pub(crate) enum Subscription<MSG> {
+    Every(Duration, Callback<(), MSG>),
+    /// emitted with the milliseconds since the last frame
+    AnimationFrame(Callback<f64, MSG>),
+    /// emitted with the width and height of the window in pixels
+    Resize(Callback<(i32, i32), MSG>),
+}
+
+/// The subscriptions of a component
+pub struct Sub<MSG> {
+    subscriptions: Vec<Subscription<MSG>>,
+}
+
+impl<MSG> Sub<MSG>
+where
+    MSG: 'static,
+{
+    pub fn none() -> Self {
+        Sub {
+            subscriptions: vec![],
+        }
+    }
+
+    /// emit the message every interval
+    pub fn every<F>(interval: Duration, msg: F) -> Self
+    where
+        F: Fn() -> MSG + 'static,
+    {
+        Sub {
+            subscriptions: vec![Subscription::Every(
+                interval,
+                Callback::from(move |_: ()| msg()),
+            )],
+        }
+    }
+
+    /// emit the message before every frame is drawn, with the milliseconds
+    /// since the last frame. The frames of the terminal backends are as often as their ticks
+    pub fn animation_frame<F>(msg: F) -> Self
+    where
+        F: Fn(f64) -> MSG + 'static,
+    {
+        Sub {
+            subscriptions: vec![Subscription::AnimationFrame(Callback::from(msg))],
+        }
+    }
+
+    /// emit the message with the width and height of the window in pixels when it is resized,
+    /// the size of the terminal is converted from its cells
+    pub fn resize<F>(msg: F) -> Self
+    where
+        F: Fn(i32, i32) -> MSG + 'static,
+    {
+        Sub {
+            subscriptions: vec![Subscription::Resize(Callback::from(
+                move |(width, height): (i32, i32)| msg(width, height),
+            ))],
+        }
+    }
+
+    pub fn batch(subs: Vec<Sub<MSG>>) -> Self {
+        Sub {
+            subscriptions: subs.into_iter().flat_map(|sub| sub.subscriptions).collect(),
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.subscriptions.is_empty()
+    }
+
+    /// wrap the messages of the subscriptions of a sub component
+    /// into the messages of its parent
+    pub fn map_msg<F, PMSG>(self, f: F) -> Sub<PMSG>
+    where
+        F: Fn(MSG) -> PMSG + 'static,
+        PMSG: 'static,
+    {
+        let f = Rc::new(f);
+        let subscriptions = self
+            .subscriptions
+            .into_iter()
+            .map(|subscription| {
+                let f = Rc::clone(&f);
+                match subscription {
+                    Subscription::Every(interval, cb) => Subscription::Every(
+                        interval,
+                        Callback::from(move |_: ()| f(cb.emit(()))),
+                    ),
+                    Subscription::AnimationFrame(cb) => Subscription::AnimationFrame(
+                        Callback::from(move |elapsed: f64| f(cb.emit(elapsed))),
+                    ),
+                    Subscription::Resize(cb) => Subscription::Resize(Callback::from(
+                        move |size: (i32, i32)| f(cb.emit(size)),
+                    )),
+                }
+            })
+            .collect();
+        Sub { subscriptions }
+    }
+
+    /// the sources listened to, without duplicates
+    pub(crate) fn sources(&self) -> Vec<Source> {
+        let mut sources = vec![];
+        for subscription in &self.subscriptions {
+            let source = match subscription {
+                Subscription::Every(interval, _) => Source::Every(*interval),
+                Subscription::AnimationFrame(_) => Source::AnimationFrame,
+                Subscription::Resize(_) => Source::Resize,
+            };
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        sources
+    }
+
+    /// the messages of the subscriptions to the timer of this interval
+    pub(crate) fn every_messages(&self, interval: Duration) -> Vec<MSG> {
+        self.subscriptions
+            .iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::Every(every, cb) if *every == interval => Some(cb.emit(())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn animation_frame_messages(&self, elapsed: f64) -> Vec<MSG> {
+        self.subscriptions
+            .iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::AnimationFrame(cb) => Some(cb.emit(elapsed)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn resize_messages(&self, width: i32, height: i32) -> Vec<MSG> {
+        self.subscriptions
+            .iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::Resize(cb) => Some(cb.emit((width, height))),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl<MSG> Default for Sub<MSG>
+where
+    MSG: 'static,
+{
+    fn default() -> Self {
+        Sub::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_msg_of_subscriptions() {
+        let second = Duration::from_secs(1);
+        let sub = Sub::batch(vec![
+            Sub::every(second, || 1),
+            Sub::resize(|width, height| width + height),
+        ]);
+        let sub = sub.map_msg(|n: i32| n * 10);
+        assert_eq!(sub.sources(), vec![Source::Every(second), Source::Resize]);
+        assert_eq!(sub.every_messages(second), vec![10]);
+        assert!(sub.every_messages(Duration::from_secs(2)).is_empty());
+        assert_eq!(sub.resize_messages(3, 4), vec![70]);
+    }
+}
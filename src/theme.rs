@@ -0,0 +1,78 @@
+//! The look of the application which every backend applies
+//! in its own way, so the app looks alike across the platforms.
+use crate::widget::property::Color;
+
+/// The colors used throughout the application
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub background: Color,
+    pub foreground: Color,
+    /// the color of buttons and other controls the user interacts with
+    pub primary: Color,
+    /// the color of the text on top of the primary color
+    pub on_primary: Color,
+    pub border: Color,
+}
+
+/// The palette, fonts and spacing of the application
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub palette: Palette,
+    pub font_family: String,
+    /// font size in pixels
+    pub font_size: f64,
+    /// the base unit in pixels of the spacing scale
+    pub spacing_unit: f64,
+    /// border radius in pixels
+    pub border_radius: f64,
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Theme {
+            palette: Palette {
+                background: Color::rgb(255, 255, 255),
+                foreground: Color::rgb(33, 33, 33),
+                primary: Color::rgb(25, 118, 210),
+                on_primary: Color::rgb(255, 255, 255),
+                border: Color::rgb(189, 189, 189),
+            },
+            ..Self::base()
+        }
+    }
+
+    pub fn dark() -> Self {
+        Theme {
+            palette: Palette {
+                background: Color::rgb(33, 33, 33),
+                foreground: Color::rgb(238, 238, 238),
+                primary: Color::rgb(144, 202, 249),
+                on_primary: Color::rgb(33, 33, 33),
+                border: Color::rgb(97, 97, 97),
+            },
+            ..Self::base()
+        }
+    }
+
+    /// the spacing in pixels at this step of the spacing scale
+    pub fn spacing(&self, step: u32) -> f64 {
+        self.spacing_unit * step as f64
+    }
+
+    /// the fonts and spacing shared by the light and dark themes
+    fn base() -> Self {
+        Theme {
+            palette: Palette {
+                background: Color::rgb(255, 255, 255),
+                foreground: Color::rgb(0, 0, 0),
+                primary: Color::rgb(0, 0, 255),
+                on_primary: Color::rgb(255, 255, 255),
+                border: Color::rgb(128, 128, 128),
+            },
+            font_family: "sans-serif".to_string(),
+            font_size: 14.0,
+            spacing_unit: 4.0,
+            border_radius: 4.0,
+        }
+    }
+}
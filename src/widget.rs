@@ -1,10 +1,17 @@
-use crate::{AttribKey, Attribute, Node};
+use crate::{event::on, AttribKey, Attribute, Node};
+use chart::{Chart, Series};
 use control::{Button, Checkbox, TextInput};
+use icon::{Icon, IconSize};
+use property::Orientation;
 use sauron_vdom::{builder::element, Callback, Event};
-use std::fmt::Debug;
+use std::{fmt::Debug, rc::Rc};
 
 pub mod attribute;
+pub mod chart;
 mod control;
+pub mod icon;
+pub mod property;
+pub mod sizing;
 
 /// TODO: Each widget variant will need to have more details
 ///  such as attributes, that will be converted to their
@@ -23,6 +30,17 @@ pub enum Widget {
     Checkbox,
     Radio,
     Image(Vec<u8>),
+    /// a container with a title and a border around its children
+    Frame,
+    /// a line that visually splits the widgets in a column or a row
+    Separator(Orientation),
+    /// a collapsible section with a header,
+    /// the children are hidden when it is collapsed
+    Expander,
+    Icon(Icon, IconSize),
+    LineChart(Chart),
+    BarChart(Chart),
+    Sparkline(Series),
 }
 
 pub fn widget<MSG>(
@@ -33,6 +51,86 @@ pub fn widget<MSG>(
     element(widget, attrs, children)
 }
 
+/// The children of a widget in the `view!` macro,
+/// either a node, an optional node or a list of nodes
+pub trait IntoNodes<MSG> {
+    fn into_nodes(self) -> Vec<Node<MSG>>;
+}
+
+impl<MSG> IntoNodes<MSG> for Node<MSG> {
+    fn into_nodes(self) -> Vec<Node<MSG>> {
+        vec![self]
+    }
+}
+
+impl<MSG> IntoNodes<MSG> for Option<Node<MSG>> {
+    fn into_nodes(self) -> Vec<Node<MSG>> {
+        self.into_iter().collect()
+    }
+}
+
+impl<MSG> IntoNodes<MSG> for Vec<Node<MSG>> {
+    fn into_nodes(self) -> Vec<Node<MSG>> {
+        self
+    }
+}
+
+/// Embeds the view of a sub component in the view of its parent,
+/// the messages of its events are wrapped into the messages of the parent
+/// such as `self.counter.view().map_msg(Msg::Counter)`
+pub trait MapMsg<MSG> {
+    fn map_msg<F, PMSG>(self, f: F) -> Node<PMSG>
+    where
+        F: Fn(MSG) -> PMSG + 'static,
+        PMSG: 'static;
+}
+
+impl<MSG> MapMsg<MSG> for Node<MSG>
+where
+    MSG: 'static,
+{
+    fn map_msg<F, PMSG>(self, f: F) -> Node<PMSG>
+    where
+        F: Fn(MSG) -> PMSG + 'static,
+        PMSG: 'static,
+    {
+        map_node(self, &Rc::new(f))
+    }
+}
+
+fn map_node<MSG, PMSG, F>(node: Node<MSG>, f: &Rc<F>) -> Node<PMSG>
+where
+    MSG: 'static,
+    PMSG: 'static,
+    F: Fn(MSG) -> PMSG + 'static,
+{
+    match node {
+        Node::Element(element) => {
+            let attrs = element
+                .attrs
+                .iter()
+                .filter_map(|att| match att.get_callback() {
+                    Some(cb) => {
+                        let cb = cb.clone();
+                        let f = Rc::clone(f);
+                        Some(on(att.name.clone(), move |event: Event| f(cb.emit(event))))
+                    }
+                    None => att
+                        .get_value()
+                        .map(|value| attribute::attr(att.name.clone(), value.clone())),
+                })
+                .collect();
+            let children = element
+                .children
+                .into_iter()
+                .map(|child| map_node(child, f))
+                .collect();
+            widget(element.tag, attrs, children)
+        }
+        Node::Text(txt) => Node::Text(txt),
+    }
+}
+
 pub fn column<MSG>(attrs: Vec<Attribute<MSG>>, children: Vec<Node<MSG>>) -> Node<MSG> {
     widget(Widget::Vbox, attrs, children)
 }
@@ -41,6 +139,18 @@ pub fn row<MSG>(attrs: Vec<Attribute<MSG>>, children: Vec<Node<MSG>>) -> Node<MS
     widget(Widget::Hbox, attrs, children)
 }
 
+/// a titled frame grouping its children,
+/// the title is set with the `label` attribute
+pub fn frame<MSG>(attrs: Vec<Attribute<MSG>>, children: Vec<Node<MSG>>) -> Node<MSG> {
+    widget(Widget::Frame, attrs, children)
+}
+
+/// a collapsible section, the header is set with the `label` attribute
+/// and the `expanded` attribute tells whether the children are shown
+pub fn expander<MSG>(attrs: Vec<Attribute<MSG>>, children: Vec<Node<MSG>>) -> Node<MSG> {
+    widget(Widget::Expander, attrs, children)
+}
+
 pub fn button<MSG>(attrs: Vec<Attribute<MSG>>) -> Node<MSG> {
     widget(Widget::Button, attrs, vec![])
 }
@@ -49,6 +159,11 @@ pub fn text<MSG>(txt: &str) -> Node<MSG> {
     widget(Widget::Text(txt.to_string()), vec![], vec![])
 }
 
+/// a text with attributes such as the alignment
+pub fn text_with<MSG>(attrs: Vec<Attribute<MSG>>, txt: &str) -> Node<MSG> {
+    widget(Widget::Text(txt.to_string()), attrs, vec![])
+}
+
 pub fn text_input<MSG>(attrs: Vec<Attribute<MSG>>) -> Node<MSG> {
     widget(Widget::TextInput, attrs, vec![])
 }
@@ -64,3 +179,53 @@ pub fn radio<MSG>(attrs: Vec<Attribute<MSG>>) -> Node<MSG> {
 pub fn image<MSG>(image: Vec<u8>) -> Node<MSG> {
     widget(Widget::Image(image), vec![], vec![])
 }
+
+/// an image with attributes such as the alternative text in `accessible_label`
+pub fn image_with<MSG>(attrs: Vec<Attribute<MSG>>, image: Vec<u8>) -> Node<MSG> {
+    widget(Widget::Image(image), attrs, vec![])
+}
+
+pub fn separator<MSG>(orientation: Orientation) -> Node<MSG> {
+    widget(Widget::Separator(orientation), vec![], vec![])
+}
+
+pub fn icon<MSG>(icon: Icon, size: IconSize) -> Node<MSG> {
+    widget(Widget::Icon(icon, size), vec![], vec![])
+}
+
+pub fn line_chart<MSG>(chart: Chart) -> Node<MSG> {
+    widget(Widget::LineChart(chart), vec![], vec![])
+}
+
+pub fn bar_chart<MSG>(chart: Chart) -> Node<MSG> {
+    widget(Widget::BarChart(chart), vec![], vec![])
+}
+
+/// a small line chart of a single series without axes
+pub fn sparkline<MSG>(series: Series) -> Node<MSG> {
+    widget(Widget::Sparkline(series), vec![], vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::attribute::{find_callback, find_value, label, on_click};
+    use sauron_vdom::event::MouseEvent;
+
+    #[test]
+    fn map_msg_of_nested_view() {
+        let view: Node<i32> = column(vec![], vec![button(vec![label("+"), on_click(|_| 1)])]);
+        let view: Node<String> = view.map_msg(|n: i32| format!("counter {}", n));
+        let button = match &view {
+            Node::Element(column) => match &column.children[0] {
+                Node::Element(button) => button,
+                _ => panic!("expecting the button"),
+            },
+            _ => panic!("expecting the column"),
+        };
+        let cb = find_callback(AttribKey::ClickEvent, &button.attrs).expect("a click event");
+        assert_eq!(cb.emit(MouseEvent::default()), "counter 1");
+        let label = find_value(AttribKey::Label, &button.attrs).map(|v| v.to_string());
+        assert_eq!(label, Some("+".to_string()));
+    }
+}
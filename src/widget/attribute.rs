@@ -1,4 +1,12 @@
-use crate::{event::on, AttribKey, Attribute, Callback, Event, Value};
+use crate::{
+    event::on,
+    widget::{
+        icon::Icon,
+        property::{Alignment, BorderStyle, Color, Politeness},
+        sizing::Length,
+    },
+    AttribKey, Attribute, Callback, Event, Value,
+};
 
 pub fn value<V, MSG>(v: V) -> Attribute<MSG>
 where
@@ -14,6 +22,159 @@ where
     attr(AttribKey::Label, v)
 }
 
+/// the hint shown in a text input while its value is empty
+pub fn placeholder<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Placeholder, v)
+}
+
+/// the alignment of the children along the direction of a column or row,
+/// or the alignment of the text in a text widget
+pub fn alignment<MSG>(alignment: Alignment) -> Attribute<MSG> {
+    attr(AttribKey::Alignment, alignment)
+}
+
+/// the alignment of the children across the direction of a column or row
+pub fn cross_alignment<MSG>(alignment: Alignment) -> Attribute<MSG> {
+    attr(AttribKey::CrossAlignment, alignment)
+}
+
+pub fn border_style<MSG>(border_style: BorderStyle) -> Attribute<MSG> {
+    attr(AttribKey::BorderStyle, border_style)
+}
+
+/// an icon displayed next to the label of a button
+pub fn icon_name<MSG>(icon: Icon) -> Attribute<MSG> {
+    attr(AttribKey::Icon, icon)
+}
+
+pub fn expanded<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Expanded, v)
+}
+
+pub fn width<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::Width, length)
+}
+
+pub fn height<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::Height, length)
+}
+
+pub fn min_width<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::MinWidth, length)
+}
+
+pub fn min_height<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::MinHeight, length)
+}
+
+pub fn max_width<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::MaxWidth, length)
+}
+
+pub fn max_height<MSG>(length: Length) -> Attribute<MSG> {
+    attr(AttribKey::MaxHeight, length)
+}
+
+pub fn padding<MSG>(pixels: f64) -> Attribute<MSG> {
+    attr(AttribKey::Padding, pixels)
+}
+
+pub fn margin<MSG>(pixels: f64) -> Attribute<MSG> {
+    attr(AttribKey::Margin, pixels)
+}
+
+/// space between the children of a column or a row
+pub fn spacing<MSG>(pixels: f64) -> Attribute<MSG> {
+    attr(AttribKey::Spacing, pixels)
+}
+
+pub fn flex_grow<MSG>(grow: f64) -> Attribute<MSG> {
+    attr(AttribKey::FlexGrow, grow)
+}
+
+pub fn flex_shrink<MSG>(shrink: f64) -> Attribute<MSG> {
+    attr(AttribKey::FlexShrink, shrink)
+}
+
+pub fn color<MSG>(color: Color) -> Attribute<MSG> {
+    attr(AttribKey::Color, color)
+}
+
+pub fn background_color<MSG>(color: Color) -> Attribute<MSG> {
+    attr(AttribKey::BackgroundColor, color)
+}
+
+/// space separated class names, used to select the widget in style sheets
+pub fn class<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Class, v)
+}
+
+/// hide the widget without removing it from the tree,
+/// so showing it again keeps its state such as the text cursor
+pub fn visible<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Visible, v)
+}
+
+/// a stable identifier of the widget, such as in `Component::focus`
+/// or in the `Selector` of a style rule or a test
+pub fn id<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Id, v)
+}
+
+pub fn autofocus<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Autofocus, v)
+}
+
+/// the name of the widget read by assistive technologies,
+/// used as the alternative text of images and of buttons showing only an icon
+pub fn accessible_label<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::AccessibleLabel, v)
+}
+
+pub fn accessible_description<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::AccessibleDescription, v)
+}
+
+/// overrides the role of the widget, such as "heading", "alert" or "status"
+pub fn role<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Role, v)
+}
+
+/// marks the widget as a live region whose changes are announced
+pub fn live<MSG>(politeness: Politeness) -> Attribute<MSG> {
+    attr(AttribKey::Live, politeness)
+}
+
+pub fn hovered<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Hovered, v)
+}
+
+pub fn focused<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Focused, v)
+}
+
+pub fn disabled<MSG>(v: bool) -> Attribute<MSG> {
+    attr(AttribKey::Disabled, v)
+}
+
 pub fn on_input<C, MSG>(c: C) -> Attribute<MSG>
 where
     C: Into<Callback<Event, MSG>>,
@@ -28,6 +189,120 @@ where
     on(AttribKey::ClickEvent, c)
 }
 
+/// the callback receives a `MouseEvent` with the coordinate
+/// of the pointer relative to the top left of the widget
+pub fn on_double_click<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::DoubleClickEvent, c)
+}
+
+pub fn on_mouse_enter<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::MouseEnterEvent, c)
+}
+
+pub fn on_mouse_leave<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::MouseLeaveEvent, c)
+}
+
+/// the terminal backends only report the pointer moving while a button is held
+pub fn on_mouse_move<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::MouseMoveEvent, c)
+}
+
+/// the button of the `MouseEvent` tells the direction of the scroll,
+/// either `WheelUp` or `WheelDown`
+pub fn on_wheel<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::WheelEvent, c)
+}
+
+/// used in expander, checkbox and radio, the callback receives an `InputEvent`
+/// with the new state of the widget as its value, either "true" or "false"
+pub fn on_toggle<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::ToggleEvent, c)
+}
+
+/// the callback receives an `InputEvent` with the value of the widget,
+/// such as the text of a text input
+pub fn on_focus<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::FocusEvent, c)
+}
+
+/// the callback receives an `InputEvent` with the value of the widget,
+/// such as the text of a text input
+pub fn on_blur<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::BlurEvent, c)
+}
+
+/// the callback receives a `KeyEvent`, the key is named as in the `key`
+/// of the html keyboard event, such as "Enter", "Escape", "ArrowUp"
+/// or the character typed, along with the modifiers and whether
+/// the key is held down and repeating
+pub fn on_key_press<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::KeyPressEvent, c)
+}
+
+/// the terminal backends can not tell when a key is released,
+/// so this is only emitted in the html and gtk backends
+pub fn on_key_release<C, MSG>(c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::KeyReleaseEvent, c)
+}
+
+/// an html attribute set as is on the element, such as `data-testid`
+pub fn html_attr<V, MSG>(name: &str, v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Custom(format!("html:{}", name)), v)
+}
+
+/// listens to an html event by its name, such as `dragstart`,
+/// the callback receives a `MouseEvent` for the mouse events,
+/// otherwise an `InputEvent` with the value of the element
+pub fn on_html_event<C, MSG>(name: &str, c: C) -> Attribute<MSG>
+where
+    C: Into<Callback<Event, MSG>>,
+{
+    on(AttribKey::Custom(format!("html:{}", name)), c)
+}
+
+/// a gtk property set on the widget by its name, such as `tooltip-text`,
+/// the property takes a bool or a string
+pub fn gtk_property<V, MSG>(name: &str, v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(AttribKey::Custom(format!("gtk:{}", name)), v)
+}
+
 pub fn attr<V, MSG>(name: AttribKey, v: V) -> Attribute<MSG>
 where
     V: Into<Value>,
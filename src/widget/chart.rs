@@ -0,0 +1,94 @@
+//! Numeric data displayed in the chart widgets
+use crate::widget::property::Color;
+
+/// the characters used to draw values with increasing height
+/// in the backends that can only draw text
+const BLOCK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A named sequence of values drawn in a single color
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub data: Vec<f64>,
+    pub color: Color,
+}
+
+/// The series of a line or bar chart together with the axis labels
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Chart {
+    pub series: Vec<Series>,
+    /// labels along the horizontal axis, one for each data point
+    pub x_labels: Vec<String>,
+    /// labels along the vertical axis, from the bottom to the top
+    pub y_labels: Vec<String>,
+}
+
+impl Series {
+    pub fn new(name: &str, data: Vec<f64>, color: Color) -> Self {
+        Series {
+            name: name.to_string(),
+            data,
+            color,
+        }
+    }
+
+    /// the lowest and highest values in this series, always including zero
+    pub fn bounds(&self) -> (f64, f64) {
+        bounds(self.data.iter())
+    }
+
+    /// the values scaled to 0.0..=1.0 within the bounds
+    pub fn normalized(&self, (min, max): (f64, f64)) -> Vec<f64> {
+        let range = max - min;
+        self.data
+            .iter()
+            .map(|v| if range > 0.0 { (v - min) / range } else { 0.0 })
+            .collect()
+    }
+
+    /// the values drawn as block characters, one character per value
+    pub fn to_block_chars(&self) -> String {
+        self.normalized(self.bounds())
+            .iter()
+            .map(|v| BLOCK_CHARS[(v * (BLOCK_CHARS.len() - 1) as f64).round() as usize])
+            .collect()
+    }
+}
+
+impl Chart {
+    pub fn new(series: Vec<Series>) -> Self {
+        Chart {
+            series,
+            ..Default::default()
+        }
+    }
+
+    pub fn x_labels(mut self, labels: Vec<&str>) -> Self {
+        self.x_labels = labels.into_iter().map(ToString::to_string).collect();
+        self
+    }
+
+    pub fn y_labels(mut self, labels: Vec<&str>) -> Self {
+        self.y_labels = labels.into_iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// the lowest and highest values across all the series, always including zero,
+    /// so they can be drawn in the same scale
+    pub fn bounds(&self) -> (f64, f64) {
+        bounds(self.series.iter().flat_map(|series| series.data.iter()))
+    }
+
+    /// the number of data points in the longest series
+    pub fn point_count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|series| series.data.len())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn bounds<'a>(values: impl Iterator<Item = &'a f64>) -> (f64, f64) {
+    values.fold((0.0, 0.0), |(min, max), v| (min.min(*v), max.max(*v)))
+}
@@ -0,0 +1,107 @@
+//! A named icon set which each of the backends resolves
+//! to the closest icon it has available.
+use sauron_vdom::Value;
+
+/// Semantic name of an icon
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Icon {
+    Save,
+    Open,
+    Delete,
+    Warning,
+    Error,
+    Info,
+    Add,
+    Remove,
+    Edit,
+    Search,
+    Close,
+    Refresh,
+}
+
+/// The size in which an icon is displayed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IconSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Icon {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Icon::Save => "save",
+            Icon::Open => "open",
+            Icon::Delete => "delete",
+            Icon::Warning => "warning",
+            Icon::Error => "error",
+            Icon::Info => "info",
+            Icon::Add => "add",
+            Icon::Remove => "remove",
+            Icon::Edit => "edit",
+            Icon::Search => "search",
+            Icon::Close => "close",
+            Icon::Refresh => "refresh",
+        }
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match &*value.to_string() {
+            "save" => Some(Icon::Save),
+            "open" => Some(Icon::Open),
+            "delete" => Some(Icon::Delete),
+            "warning" => Some(Icon::Warning),
+            "error" => Some(Icon::Error),
+            "info" => Some(Icon::Info),
+            "add" => Some(Icon::Add),
+            "remove" => Some(Icon::Remove),
+            "edit" => Some(Icon::Edit),
+            "search" => Some(Icon::Search),
+            "close" => Some(Icon::Close),
+            "refresh" => Some(Icon::Refresh),
+            _ => None,
+        }
+    }
+
+    /// a single cell unicode character for the backends
+    /// that can not draw graphics such as the terminal
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Icon::Save => "⤓",
+            Icon::Open => "⤒",
+            Icon::Delete => "✗",
+            Icon::Warning => "⚠",
+            Icon::Error => "⊗",
+            Icon::Info => "ℹ",
+            Icon::Add => "+",
+            Icon::Remove => "−",
+            Icon::Edit => "✎",
+            Icon::Search => "⌕",
+            Icon::Close => "×",
+            Icon::Refresh => "↻",
+        }
+    }
+}
+
+impl IconSize {
+    /// the width and height of the icon in pixels
+    pub fn pixels(&self) -> u32 {
+        match self {
+            IconSize::Small => 16,
+            IconSize::Medium => 24,
+            IconSize::Large => 48,
+        }
+    }
+}
+
+impl Default for IconSize {
+    fn default() -> Self {
+        IconSize::Small
+    }
+}
+
+impl From<Icon> for Value {
+    fn from(icon: Icon) -> Self {
+        Value::from(icon.as_str())
+    }
+}
@@ -0,0 +1,199 @@
+//! Typed property values that are passed around as attribute values.
+//!
+//! Attribute values are stored as a `sauron_vdom::Value`, so each of these
+//! types converts into a string `Value` and can be parsed back by the backends.
+use sauron_vdom::Value;
+
+/// The style of the border drawn around a container such as a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderStyle {
+    None,
+    Solid,
+    Dashed,
+    Double,
+}
+
+impl BorderStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BorderStyle::None => "none",
+            BorderStyle::Solid => "solid",
+            BorderStyle::Dashed => "dashed",
+            BorderStyle::Double => "double",
+        }
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match &*value.to_string() {
+            "none" => Some(BorderStyle::None),
+            "solid" => Some(BorderStyle::Solid),
+            "dashed" => Some(BorderStyle::Dashed),
+            "double" => Some(BorderStyle::Double),
+            _ => None,
+        }
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        BorderStyle::Solid
+    }
+}
+
+impl From<BorderStyle> for Value {
+    fn from(border_style: BorderStyle) -> Self {
+        Value::from(border_style.as_str())
+    }
+}
+
+/// The direction in which a widget such as a separator is laid out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// How the children of a column or row, or the text of a widget are aligned
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alignment {
+    Start,
+    Center,
+    End,
+    /// fill the available space
+    Stretch,
+    /// distribute the free space between the children,
+    /// text is justified
+    SpaceBetween,
+}
+
+impl Alignment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alignment::Start => "start",
+            Alignment::Center => "center",
+            Alignment::End => "end",
+            Alignment::Stretch => "stretch",
+            Alignment::SpaceBetween => "space-between",
+        }
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match &*value.to_string() {
+            "start" => Some(Alignment::Start),
+            "center" => Some(Alignment::Center),
+            "end" => Some(Alignment::End),
+            "stretch" => Some(Alignment::Stretch),
+            "space-between" => Some(Alignment::SpaceBetween),
+            _ => None,
+        }
+    }
+}
+
+impl From<Alignment> for Value {
+    fn from(alignment: Alignment) -> Self {
+        Value::from(alignment.as_str())
+    }
+}
+
+/// How eagerly assistive technologies announce the changes
+/// in the content of a live region
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Politeness {
+    Off,
+    /// announced when the user is idle
+    Polite,
+    /// announced right away, interrupting the user
+    Assertive,
+}
+
+impl Politeness {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Politeness::Off => "off",
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        }
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match &*value.to_string() {
+            "off" => Some(Politeness::Off),
+            "polite" => Some(Politeness::Polite),
+            "assertive" => Some(Politeness::Assertive),
+            _ => None,
+        }
+    }
+}
+
+impl From<Politeness> for Value {
+    fn from(politeness: Politeness) -> Self {
+        Value::from(politeness.as_str())
+    }
+}
+
+/// A color in rgb
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color { red, green, blue }
+    }
+
+    /// the color in `#rrggbb` notation
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    /// each of the components scaled to 0.0..1.0
+    pub fn to_rgb_f64(&self) -> (f64, f64, f64) {
+        (
+            self.red as f64 / 255.0,
+            self.green as f64 / 255.0,
+            self.blue as f64 / 255.0,
+        )
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let hex = value.to_string();
+        let hex = hex.trim_start_matches('#');
+        // the length is checked in bytes, so the slicing
+        // can only be done on ascii digits
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Color::rgb(component(0)?, component(2)?, component(4)?))
+    }
+}
+
+impl From<Color> for Value {
+    fn from(color: Color) -> Self {
+        Value::from(color.to_hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_from_hex() {
+        let color = Color::from_value(&Value::from("#ff8000"));
+        assert_eq!(color, Some(Color::rgb(255, 128, 0)));
+        assert_eq!(color.map(|c| c.to_hex()), Some("#ff8000".to_string()));
+    }
+
+    #[test]
+    fn color_from_invalid() {
+        assert_eq!(Color::from_value(&Value::from("#ff80")), None);
+        assert_eq!(Color::from_value(&Value::from("#gg8000")), None);
+        // 6 bytes but not 6 characters
+        assert_eq!(Color::from_value(&Value::from("ééé")), None);
+        assert_eq!(Color::from_value(&Value::from("#+f+f+f")), None);
+    }
+}
@@ -0,0 +1,89 @@
+//! The sizing attributes of a widget, collected from its attributes
+//! so each backend can translate them into its own layout properties.
+use crate::{widget::attribute::find_value, AttribKey, Attribute};
+use sauron_vdom::Value;
+
+/// A length along the width or height of a widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// fixed length in pixels, terminal backends convert it into cells
+    Px(f64),
+    /// percentage of the size of the parent
+    Percent(f64),
+}
+
+impl Length {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let value = value.to_string();
+        if value.ends_with("px") {
+            value.trim_end_matches("px").parse().ok().map(Length::Px)
+        } else if value.ends_with('%') {
+            value
+                .trim_end_matches('%')
+                .parse()
+                .ok()
+                .map(Length::Percent)
+        } else {
+            None
+        }
+    }
+
+    /// the length in css notation
+    pub fn to_css(&self) -> String {
+        match self {
+            Length::Px(px) => format!("{}px", px),
+            Length::Percent(percent) => format!("{}%", percent),
+        }
+    }
+}
+
+impl From<Length> for Value {
+    fn from(length: Length) -> Self {
+        Value::from(length.to_css())
+    }
+}
+
+/// The size and spacing preferences of a widget,
+/// unset properties are left to the defaults of the backend
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sizing {
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub min_width: Option<Length>,
+    pub min_height: Option<Length>,
+    pub max_width: Option<Length>,
+    pub max_height: Option<Length>,
+    /// space in pixels between the border and the content of the widget
+    pub padding: Option<f64>,
+    /// space in pixels around the border of the widget
+    pub margin: Option<f64>,
+    /// space in pixels between the children of a column or row
+    pub spacing: Option<f64>,
+    /// how much of the remaining space in the parent this widget takes
+    pub flex_grow: Option<f64>,
+    /// how much this widget shrinks when the parent is too small
+    pub flex_shrink: Option<f64>,
+}
+
+impl Sizing {
+    pub fn from_attrs<MSG>(attrs: &Vec<Attribute<MSG>>) -> Self
+    where
+        MSG: 'static,
+    {
+        let length = |key| find_value(key, attrs).map(Length::from_value).flatten();
+        let number = |key| find_value(key, attrs).map(|v| v.as_f64()).flatten();
+        Sizing {
+            width: length(AttribKey::Width),
+            height: length(AttribKey::Height),
+            min_width: length(AttribKey::MinWidth),
+            min_height: length(AttribKey::MinHeight),
+            max_width: length(AttribKey::MaxWidth),
+            max_height: length(AttribKey::MaxHeight),
+            padding: number(AttribKey::Padding),
+            margin: number(AttribKey::Margin),
+            spacing: number(AttribKey::Spacing),
+            flex_grow: number(AttribKey::FlexGrow),
+            flex_shrink: number(AttribKey::FlexShrink),
+        }
+    }
+}